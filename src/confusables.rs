@@ -0,0 +1,375 @@
+//! Chars that look like ASCII chars, from the Unicode confusables data.
+
+/// The ASCII char that `c` can be confused with, if any
+pub(crate) fn ascii_lookalike(c: char) -> Option<char> {
+    let i = CONFUSABLES.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+    Some(CONFUSABLES[i].1)
+}
+
+// Generated from `confusables.txt` of Unicode 16.0.0: the non-ASCII chars whose
+// prototype is a single printable ASCII char, sorted by char.
+#[rustfmt::skip]
+const CONFUSABLES: &[(char, char)] = &[
+    ('\u{a0}', ' '), ('\u{b4}', '\''), ('\u{b8}', ','), ('\u{d7}', 'x'), ('\u{131}', 'i'),
+    ('\u{17f}', 'f'), ('\u{184}', 'b'), ('\u{18d}', 'g'), ('\u{196}', 'l'), ('\u{1a6}', 'R'),
+    ('\u{1a7}', '2'), ('\u{1b7}', '3'), ('\u{1bc}', '5'), ('\u{1bd}', 's'), ('\u{1c0}', 'l'),
+    ('\u{1c3}', '!'), ('\u{21c}', '3'), ('\u{222}', '8'), ('\u{223}', '8'), ('\u{241}', '?'),
+    ('\u{251}', 'a'), ('\u{261}', 'g'), ('\u{263}', 'y'), ('\u{269}', 'i'), ('\u{26a}', 'i'),
+    ('\u{26f}', 'w'), ('\u{28b}', 'u'), ('\u{28f}', 'y'), ('\u{294}', '?'), ('\u{2b9}', '\''),
+    ('\u{2bb}', '\''), ('\u{2bc}', '\''), ('\u{2bd}', '\''), ('\u{2be}', '\''), ('\u{2c2}', '<'),
+    ('\u{2c3}', '>'), ('\u{2c4}', '^'), ('\u{2c6}', '^'), ('\u{2c8}', '\''), ('\u{2ca}', '\''),
+    ('\u{2cb}', '\''), ('\u{2d0}', ':'), ('\u{2d7}', '-'), ('\u{2db}', 'i'), ('\u{2dc}', '~'),
+    ('\u{2f4}', '\''), ('\u{2f8}', ':'), ('\u{374}', '\''), ('\u{37a}', 'i'), ('\u{37e}', ';'),
+    ('\u{37f}', 'J'), ('\u{384}', '\''), ('\u{391}', 'A'), ('\u{392}', 'B'), ('\u{395}', 'E'),
+    ('\u{396}', 'Z'), ('\u{397}', 'H'), ('\u{399}', 'l'), ('\u{39a}', 'K'), ('\u{39c}', 'M'),
+    ('\u{39d}', 'N'), ('\u{39f}', 'O'), ('\u{3a1}', 'P'), ('\u{3a4}', 'T'), ('\u{3a5}', 'Y'),
+    ('\u{3a7}', 'X'), ('\u{3b1}', 'a'), ('\u{3b3}', 'y'), ('\u{3b9}', 'i'), ('\u{3bd}', 'v'),
+    ('\u{3bf}', 'o'), ('\u{3c1}', 'p'), ('\u{3c3}', 'o'), ('\u{3c5}', 'u'), ('\u{3d2}', 'Y'),
+    ('\u{3dc}', 'F'), ('\u{3e8}', '2'), ('\u{3f1}', 'p'), ('\u{3f2}', 'c'), ('\u{3f3}', 'j'),
+    ('\u{3f9}', 'C'), ('\u{3fa}', 'M'), ('\u{405}', 'S'), ('\u{406}', 'l'), ('\u{408}', 'J'),
+    ('\u{410}', 'A'), ('\u{412}', 'B'), ('\u{415}', 'E'), ('\u{417}', '3'), ('\u{41a}', 'K'),
+    ('\u{41c}', 'M'), ('\u{41d}', 'H'), ('\u{41e}', 'O'), ('\u{420}', 'P'), ('\u{421}', 'C'),
+    ('\u{422}', 'T'), ('\u{423}', 'Y'), ('\u{425}', 'X'), ('\u{42c}', 'b'), ('\u{430}', 'a'),
+    ('\u{431}', '6'), ('\u{433}', 'r'), ('\u{435}', 'e'), ('\u{43e}', 'o'), ('\u{440}', 'p'),
+    ('\u{441}', 'c'), ('\u{443}', 'y'), ('\u{445}', 'x'), ('\u{455}', 's'), ('\u{456}', 'i'),
+    ('\u{458}', 'j'), ('\u{461}', 'w'), ('\u{474}', 'V'), ('\u{475}', 'v'), ('\u{4ae}', 'Y'),
+    ('\u{4af}', 'y'), ('\u{4bb}', 'h'), ('\u{4bd}', 'e'), ('\u{4c0}', 'l'), ('\u{4cf}', 'i'),
+    ('\u{4e0}', '3'), ('\u{501}', 'd'), ('\u{50c}', 'G'), ('\u{51b}', 'q'), ('\u{51c}', 'W'),
+    ('\u{51d}', 'w'), ('\u{54d}', 'U'), ('\u{54f}', 'S'), ('\u{555}', 'O'), ('\u{55a}', '\''),
+    ('\u{55d}', '\''), ('\u{561}', 'w'), ('\u{563}', 'q'), ('\u{566}', 'q'), ('\u{570}', 'h'),
+    ('\u{578}', 'n'), ('\u{57c}', 'n'), ('\u{57d}', 'u'), ('\u{581}', 'g'), ('\u{584}', 'f'),
+    ('\u{585}', 'o'), ('\u{589}', ':'), ('\u{5c0}', 'l'), ('\u{5c3}', ':'), ('\u{5d5}', 'l'),
+    ('\u{5d8}', 'v'), ('\u{5d9}', '\''), ('\u{5df}', 'l'), ('\u{5e1}', 'o'), ('\u{5f3}', '\''),
+    ('\u{60d}', ','), ('\u{627}', 'l'), ('\u{647}', 'o'), ('\u{660}', '.'), ('\u{661}', 'l'),
+    ('\u{665}', 'o'), ('\u{667}', 'V'), ('\u{66b}', ','), ('\u{66d}', '*'), ('\u{6be}', 'o'),
+    ('\u{6c1}', 'o'), ('\u{6d4}', '-'), ('\u{6d5}', 'o'), ('\u{6f0}', '.'), ('\u{6f1}', 'l'),
+    ('\u{6f5}', 'o'), ('\u{6f7}', 'V'), ('\u{701}', '.'), ('\u{702}', '.'), ('\u{703}', ':'),
+    ('\u{704}', ':'), ('\u{7c0}', 'O'), ('\u{7ca}', 'l'), ('\u{7f4}', '\''), ('\u{7f5}', '\''),
+    ('\u{7fa}', '_'), ('\u{903}', ':'), ('\u{966}', 'o'), ('\u{97d}', '?'), ('\u{9e6}', 'O'),
+    ('\u{9ea}', '8'), ('\u{9ed}', '9'), ('\u{a66}', 'o'), ('\u{a67}', '9'), ('\u{a6a}', '8'),
+    ('\u{a83}', ':'), ('\u{ae6}', 'o'), ('\u{b03}', '8'), ('\u{b20}', 'O'), ('\u{b66}', 'O'),
+    ('\u{b68}', '9'), ('\u{be6}', 'o'), ('\u{c02}', 'o'), ('\u{c66}', 'o'), ('\u{c82}', 'o'),
+    ('\u{ce6}', 'o'), ('\u{d02}', 'o'), ('\u{d20}', 'o'), ('\u{d66}', 'o'), ('\u{d6d}', '9'),
+    ('\u{d82}', 'o'), ('\u{e50}', 'o'), ('\u{ed0}', 'o'), ('\u{101d}', 'o'), ('\u{1040}', 'o'),
+    ('\u{10e7}', 'y'), ('\u{10ff}', 'o'), ('\u{1200}', 'U'), ('\u{12d0}', 'O'), ('\u{13a0}', 'D'),
+    ('\u{13a1}', 'R'), ('\u{13a2}', 'T'), ('\u{13a5}', 'i'), ('\u{13a9}', 'Y'), ('\u{13aa}', 'A'),
+    ('\u{13ab}', 'J'), ('\u{13ac}', 'E'), ('\u{13ae}', '?'), ('\u{13b3}', 'W'), ('\u{13b7}', 'M'),
+    ('\u{13bb}', 'H'), ('\u{13bd}', 'Y'), ('\u{13c0}', 'G'), ('\u{13c2}', 'h'), ('\u{13c3}', 'Z'),
+    ('\u{13ce}', '4'), ('\u{13cf}', 'b'), ('\u{13d2}', 'R'), ('\u{13d4}', 'W'), ('\u{13d5}', 'S'),
+    ('\u{13d9}', 'V'), ('\u{13da}', 'S'), ('\u{13de}', 'L'), ('\u{13df}', 'C'), ('\u{13e2}', 'P'),
+    ('\u{13e6}', 'K'), ('\u{13e7}', 'd'), ('\u{13ee}', '6'), ('\u{13f3}', 'G'), ('\u{13f4}', 'B'),
+    ('\u{1400}', '='), ('\u{142f}', 'V'), ('\u{1433}', '>'), ('\u{1438}', '<'), ('\u{144a}', '\''),
+    ('\u{144c}', 'U'), ('\u{146d}', 'P'), ('\u{146f}', 'd'), ('\u{1472}', 'b'), ('\u{148d}', 'J'),
+    ('\u{14aa}', 'L'), ('\u{14bf}', '2'), ('\u{1541}', 'x'), ('\u{157c}', 'H'), ('\u{157d}', 'x'),
+    ('\u{1587}', 'R'), ('\u{15af}', 'b'), ('\u{15b4}', 'F'), ('\u{15c5}', 'A'), ('\u{15de}', 'D'),
+    ('\u{15ea}', 'D'), ('\u{15f0}', 'M'), ('\u{15f7}', 'B'), ('\u{166d}', 'X'), ('\u{166e}', 'x'),
+    ('\u{1680}', ' '), ('\u{16b2}', '<'), ('\u{16b7}', 'X'), ('\u{16c1}', 'l'), ('\u{16cc}', '\''),
+    ('\u{16d5}', 'K'), ('\u{16d6}', 'M'), ('\u{16ec}', ':'), ('\u{16ed}', '+'), ('\u{1735}', '/'),
+    ('\u{1803}', ':'), ('\u{1809}', ':'), ('\u{1d04}', 'c'), ('\u{1d0f}', 'o'), ('\u{1d11}', 'o'),
+    ('\u{1d1c}', 'u'), ('\u{1d20}', 'v'), ('\u{1d21}', 'w'), ('\u{1d22}', 'z'), ('\u{1d26}', 'r'),
+    ('\u{1d83}', 'g'), ('\u{1d8c}', 'y'), ('\u{1e9d}', 'f'), ('\u{1eff}', 'y'), ('\u{1fbd}', '\''),
+    ('\u{1fbe}', 'i'), ('\u{1fbf}', '\''), ('\u{1fc0}', '~'), ('\u{1fef}', '\''),
+    ('\u{1ffd}', '\''), ('\u{1ffe}', '\''), ('\u{2000}', ' '), ('\u{2001}', ' '), ('\u{2002}', ' '),
+    ('\u{2003}', ' '), ('\u{2004}', ' '), ('\u{2005}', ' '), ('\u{2006}', ' '), ('\u{2007}', ' '),
+    ('\u{2008}', ' '), ('\u{2009}', ' '), ('\u{200a}', ' '), ('\u{2010}', '-'), ('\u{2011}', '-'),
+    ('\u{2012}', '-'), ('\u{2013}', '-'), ('\u{2018}', '\''), ('\u{2019}', '\''), ('\u{201a}', ','),
+    ('\u{201b}', '\''), ('\u{2024}', '.'), ('\u{2028}', ' '), ('\u{2029}', ' '), ('\u{202f}', ' '),
+    ('\u{2032}', '\''), ('\u{2035}', '\''), ('\u{2039}', '<'), ('\u{203a}', '>'), ('\u{2041}', '/'),
+    ('\u{2043}', '-'), ('\u{2044}', '/'), ('\u{204e}', '*'), ('\u{2053}', '~'), ('\u{205a}', ':'),
+    ('\u{205f}', ' '), ('\u{2102}', 'C'), ('\u{210a}', 'g'), ('\u{210b}', 'H'), ('\u{210c}', 'H'),
+    ('\u{210d}', 'H'), ('\u{210e}', 'h'), ('\u{2110}', 'l'), ('\u{2111}', 'l'), ('\u{2112}', 'L'),
+    ('\u{2113}', 'l'), ('\u{2115}', 'N'), ('\u{2119}', 'P'), ('\u{211a}', 'Q'), ('\u{211b}', 'R'),
+    ('\u{211c}', 'R'), ('\u{211d}', 'R'), ('\u{2124}', 'Z'), ('\u{2128}', 'Z'), ('\u{212a}', 'K'),
+    ('\u{212c}', 'B'), ('\u{212d}', 'C'), ('\u{212e}', 'e'), ('\u{212f}', 'e'), ('\u{2130}', 'E'),
+    ('\u{2131}', 'F'), ('\u{2133}', 'M'), ('\u{2134}', 'o'), ('\u{2139}', 'i'), ('\u{213d}', 'y'),
+    ('\u{2145}', 'D'), ('\u{2146}', 'd'), ('\u{2147}', 'e'), ('\u{2148}', 'i'), ('\u{2149}', 'j'),
+    ('\u{2160}', 'l'), ('\u{2164}', 'V'), ('\u{2169}', 'X'), ('\u{216c}', 'L'), ('\u{216d}', 'C'),
+    ('\u{216e}', 'D'), ('\u{216f}', 'M'), ('\u{2170}', 'i'), ('\u{2174}', 'v'), ('\u{2179}', 'x'),
+    ('\u{217c}', 'l'), ('\u{217d}', 'c'), ('\u{217e}', 'd'), ('\u{2212}', '-'), ('\u{2215}', '/'),
+    ('\u{2216}', '\\'), ('\u{2217}', '*'), ('\u{2223}', 'l'), ('\u{2228}', 'v'), ('\u{222a}', 'U'),
+    ('\u{2236}', ':'), ('\u{223c}', '~'), ('\u{22a4}', 'T'), ('\u{22c1}', 'v'), ('\u{22c3}', 'U'),
+    ('\u{22ff}', 'E'), ('\u{2373}', 'i'), ('\u{2374}', 'p'), ('\u{237a}', 'a'), ('\u{23fd}', 'l'),
+    ('\u{2571}', '/'), ('\u{2573}', 'X'), ('\u{2768}', '('), ('\u{2769}', ')'), ('\u{276e}', '<'),
+    ('\u{276f}', '>'), ('\u{2772}', '('), ('\u{2773}', ')'), ('\u{2774}', '{'), ('\u{2775}', '}'),
+    ('\u{2795}', '+'), ('\u{2796}', '-'), ('\u{27cb}', '/'), ('\u{27cd}', '\\'), ('\u{27d9}', 'T'),
+    ('\u{292b}', 'x'), ('\u{292c}', 'x'), ('\u{29f5}', '\\'), ('\u{29f8}', '/'), ('\u{29f9}', '\\'),
+    ('\u{2a2f}', 'x'), ('\u{2c85}', 'r'), ('\u{2c8e}', 'H'), ('\u{2c92}', 'l'), ('\u{2c94}', 'K'),
+    ('\u{2c98}', 'M'), ('\u{2c9a}', 'N'), ('\u{2c9e}', 'O'), ('\u{2c9f}', 'o'), ('\u{2ca2}', 'P'),
+    ('\u{2ca3}', 'p'), ('\u{2ca4}', 'C'), ('\u{2ca5}', 'c'), ('\u{2ca6}', 'T'), ('\u{2ca8}', 'Y'),
+    ('\u{2cac}', 'X'), ('\u{2cba}', '-'), ('\u{2cc6}', '/'), ('\u{2cca}', '9'), ('\u{2ccc}', '3'),
+    ('\u{2cd0}', 'L'), ('\u{2cd2}', '6'), ('\u{2d38}', 'V'), ('\u{2d39}', 'E'), ('\u{2d4f}', 'l'),
+    ('\u{2d51}', '!'), ('\u{2d54}', 'O'), ('\u{2d55}', 'Q'), ('\u{2d5d}', 'X'), ('\u{2e40}', '='),
+    ('\u{2f02}', '\\'), ('\u{2f03}', '/'), ('\u{3007}', 'O'), ('\u{3014}', '('), ('\u{3015}', ')'),
+    ('\u{3033}', '/'), ('\u{30a0}', '='), ('\u{30ce}', '/'), ('\u{31d3}', '/'), ('\u{31d4}', '\\'),
+    ('\u{4e36}', '\\'), ('\u{4e3f}', '/'), ('\u{a4d0}', 'B'), ('\u{a4d1}', 'P'), ('\u{a4d2}', 'd'),
+    ('\u{a4d3}', 'D'), ('\u{a4d4}', 'T'), ('\u{a4d6}', 'G'), ('\u{a4d7}', 'K'), ('\u{a4d9}', 'J'),
+    ('\u{a4da}', 'C'), ('\u{a4dc}', 'Z'), ('\u{a4dd}', 'F'), ('\u{a4df}', 'M'), ('\u{a4e0}', 'N'),
+    ('\u{a4e1}', 'L'), ('\u{a4e2}', 'S'), ('\u{a4e3}', 'R'), ('\u{a4e6}', 'V'), ('\u{a4e7}', 'H'),
+    ('\u{a4ea}', 'W'), ('\u{a4eb}', 'X'), ('\u{a4ec}', 'Y'), ('\u{a4ee}', 'A'), ('\u{a4f0}', 'E'),
+    ('\u{a4f2}', 'l'), ('\u{a4f3}', 'O'), ('\u{a4f4}', 'U'), ('\u{a4f8}', '.'), ('\u{a4f9}', ','),
+    ('\u{a4fd}', ':'), ('\u{a4ff}', '='), ('\u{a60e}', '.'), ('\u{a644}', '2'), ('\u{a647}', 'i'),
+    ('\u{a6df}', 'V'), ('\u{a6eb}', '?'), ('\u{a6ef}', '2'), ('\u{a731}', 's'), ('\u{a75a}', '2'),
+    ('\u{a76a}', '3'), ('\u{a76e}', '9'), ('\u{a778}', '&'), ('\u{a789}', ':'), ('\u{a78c}', '\''),
+    ('\u{a798}', 'F'), ('\u{a799}', 'f'), ('\u{a79f}', 'u'), ('\u{a7ab}', '3'), ('\u{a7b2}', 'J'),
+    ('\u{a7b3}', 'X'), ('\u{a7b4}', 'B'), ('\u{ab32}', 'e'), ('\u{ab35}', 'f'), ('\u{ab3d}', 'o'),
+    ('\u{ab47}', 'r'), ('\u{ab48}', 'r'), ('\u{ab4e}', 'u'), ('\u{ab52}', 'u'), ('\u{ab5a}', 'y'),
+    ('\u{ab75}', 'i'), ('\u{ab81}', 'r'), ('\u{ab83}', 'w'), ('\u{ab93}', 'z'), ('\u{aba9}', 'v'),
+    ('\u{abaa}', 's'), ('\u{abaf}', 'c'), ('\u{fba6}', 'o'), ('\u{fba7}', 'o'), ('\u{fba8}', 'o'),
+    ('\u{fba9}', 'o'), ('\u{fbaa}', 'o'), ('\u{fbab}', 'o'), ('\u{fbac}', 'o'), ('\u{fbad}', 'o'),
+    ('\u{fd3e}', '('), ('\u{fd3f}', ')'), ('\u{fe30}', ':'), ('\u{fe4d}', '_'), ('\u{fe4e}', '_'),
+    ('\u{fe4f}', '_'), ('\u{fe58}', '-'), ('\u{fe68}', '\\'), ('\u{fe8d}', 'l'), ('\u{fe8e}', 'l'),
+    ('\u{fee9}', 'o'), ('\u{feea}', 'o'), ('\u{feeb}', 'o'), ('\u{feec}', 'o'), ('\u{ff01}', '!'),
+    ('\u{ff07}', '\''), ('\u{ff1a}', ':'), ('\u{ff21}', 'A'), ('\u{ff22}', 'B'), ('\u{ff23}', 'C'),
+    ('\u{ff25}', 'E'), ('\u{ff28}', 'H'), ('\u{ff29}', 'l'), ('\u{ff2a}', 'J'), ('\u{ff2b}', 'K'),
+    ('\u{ff2d}', 'M'), ('\u{ff2e}', 'N'), ('\u{ff2f}', 'O'), ('\u{ff30}', 'P'), ('\u{ff33}', 'S'),
+    ('\u{ff34}', 'T'), ('\u{ff38}', 'X'), ('\u{ff39}', 'Y'), ('\u{ff3a}', 'Z'), ('\u{ff3b}', '('),
+    ('\u{ff3c}', '\\'), ('\u{ff3d}', ')'), ('\u{ff40}', '\''), ('\u{ff41}', 'a'), ('\u{ff43}', 'c'),
+    ('\u{ff45}', 'e'), ('\u{ff47}', 'g'), ('\u{ff48}', 'h'), ('\u{ff49}', 'i'), ('\u{ff4a}', 'j'),
+    ('\u{ff4c}', 'l'), ('\u{ff4f}', 'o'), ('\u{ff50}', 'p'), ('\u{ff53}', 's'), ('\u{ff56}', 'v'),
+    ('\u{ff58}', 'x'), ('\u{ff59}', 'y'), ('\u{ffe8}', 'l'), ('\u{10282}', 'B'), ('\u{10286}', 'E'),
+    ('\u{10287}', 'F'), ('\u{1028a}', 'l'), ('\u{10290}', 'X'), ('\u{10292}', 'O'),
+    ('\u{10295}', 'P'), ('\u{10296}', 'S'), ('\u{10297}', 'T'), ('\u{1029b}', '+'),
+    ('\u{102a0}', 'A'), ('\u{102a1}', 'B'), ('\u{102a2}', 'C'), ('\u{102a5}', 'F'),
+    ('\u{102ab}', 'O'), ('\u{102b0}', 'M'), ('\u{102b1}', 'T'), ('\u{102b2}', 'Y'),
+    ('\u{102b4}', 'X'), ('\u{102cf}', 'H'), ('\u{102f5}', 'Z'), ('\u{10301}', 'B'),
+    ('\u{10302}', 'C'), ('\u{10309}', 'l'), ('\u{10311}', 'M'), ('\u{10315}', 'T'),
+    ('\u{10317}', 'X'), ('\u{1031a}', '8'), ('\u{1031f}', '*'), ('\u{10320}', 'l'),
+    ('\u{10322}', 'X'), ('\u{10404}', 'O'), ('\u{10415}', 'C'), ('\u{1041b}', 'L'),
+    ('\u{10420}', 'S'), ('\u{1042c}', 'o'), ('\u{1043d}', 'c'), ('\u{10448}', 's'),
+    ('\u{104b4}', 'R'), ('\u{104c2}', 'O'), ('\u{104ce}', 'U'), ('\u{104d2}', '7'),
+    ('\u{104ea}', 'o'), ('\u{104f6}', 'u'), ('\u{10513}', 'N'), ('\u{10516}', 'O'),
+    ('\u{10518}', 'K'), ('\u{1051c}', 'C'), ('\u{1051d}', 'V'), ('\u{10525}', 'F'),
+    ('\u{10526}', 'L'), ('\u{10527}', 'X'), ('\u{10a50}', '.'), ('\u{114d0}', 'O'),
+    ('\u{11706}', 'v'), ('\u{1170a}', 'w'), ('\u{1170e}', 'w'), ('\u{1170f}', 'w'),
+    ('\u{118a0}', 'V'), ('\u{118a2}', 'F'), ('\u{118a3}', 'L'), ('\u{118a4}', 'Y'),
+    ('\u{118a6}', 'E'), ('\u{118a9}', 'Z'), ('\u{118ac}', '9'), ('\u{118ae}', 'E'),
+    ('\u{118af}', '4'), ('\u{118b2}', 'L'), ('\u{118b5}', 'O'), ('\u{118b8}', 'U'),
+    ('\u{118bb}', '5'), ('\u{118bc}', 'T'), ('\u{118c0}', 'v'), ('\u{118c1}', 's'),
+    ('\u{118c2}', 'F'), ('\u{118c3}', 'i'), ('\u{118c4}', 'z'), ('\u{118c6}', '7'),
+    ('\u{118c8}', 'o'), ('\u{118ca}', '3'), ('\u{118cc}', '9'), ('\u{118d5}', '6'),
+    ('\u{118d6}', '9'), ('\u{118d7}', 'o'), ('\u{118d8}', 'u'), ('\u{118dc}', 'y'),
+    ('\u{118e0}', 'O'), ('\u{118e5}', 'Z'), ('\u{118e6}', 'W'), ('\u{118e9}', 'C'),
+    ('\u{118ec}', 'X'), ('\u{118ef}', 'W'), ('\u{118f2}', 'C'), ('\u{16f08}', 'V'),
+    ('\u{16f0a}', 'T'), ('\u{16f16}', 'L'), ('\u{16f28}', 'l'), ('\u{16f35}', 'R'),
+    ('\u{16f3a}', 'S'), ('\u{16f3b}', '3'), ('\u{16f3f}', '>'), ('\u{16f40}', 'A'),
+    ('\u{16f42}', 'U'), ('\u{16f43}', 'Y'), ('\u{16f51}', '\''), ('\u{16f52}', '\''),
+    ('\u{1ccd6}', 'A'), ('\u{1ccd7}', 'B'), ('\u{1ccd8}', 'C'), ('\u{1ccd9}', 'D'),
+    ('\u{1ccda}', 'E'), ('\u{1ccdb}', 'F'), ('\u{1ccdc}', 'G'), ('\u{1ccdd}', 'H'),
+    ('\u{1ccde}', 'l'), ('\u{1ccdf}', 'J'), ('\u{1cce0}', 'K'), ('\u{1cce1}', 'L'),
+    ('\u{1cce2}', 'M'), ('\u{1cce3}', 'N'), ('\u{1cce4}', 'O'), ('\u{1cce5}', 'P'),
+    ('\u{1cce6}', 'Q'), ('\u{1cce7}', 'R'), ('\u{1cce8}', 'S'), ('\u{1cce9}', 'T'),
+    ('\u{1ccea}', 'U'), ('\u{1cceb}', 'V'), ('\u{1ccec}', 'W'), ('\u{1cced}', 'X'),
+    ('\u{1ccee}', 'Y'), ('\u{1ccef}', 'Z'), ('\u{1ccf0}', 'O'), ('\u{1ccf1}', 'l'),
+    ('\u{1ccf2}', '2'), ('\u{1ccf3}', '3'), ('\u{1ccf4}', '4'), ('\u{1ccf5}', '5'),
+    ('\u{1ccf6}', '6'), ('\u{1ccf7}', '7'), ('\u{1ccf8}', '8'), ('\u{1ccf9}', '9'),
+    ('\u{1d114}', '{'), ('\u{1d16d}', '.'), ('\u{1d206}', '3'), ('\u{1d20d}', 'V'),
+    ('\u{1d20f}', '\\'), ('\u{1d212}', '7'), ('\u{1d213}', 'F'), ('\u{1d216}', 'R'),
+    ('\u{1d22a}', 'L'), ('\u{1d236}', '<'), ('\u{1d237}', '>'), ('\u{1d23a}', '/'),
+    ('\u{1d23b}', '\\'), ('\u{1d400}', 'A'), ('\u{1d401}', 'B'), ('\u{1d402}', 'C'),
+    ('\u{1d403}', 'D'), ('\u{1d404}', 'E'), ('\u{1d405}', 'F'), ('\u{1d406}', 'G'),
+    ('\u{1d407}', 'H'), ('\u{1d408}', 'l'), ('\u{1d409}', 'J'), ('\u{1d40a}', 'K'),
+    ('\u{1d40b}', 'L'), ('\u{1d40c}', 'M'), ('\u{1d40d}', 'N'), ('\u{1d40e}', 'O'),
+    ('\u{1d40f}', 'P'), ('\u{1d410}', 'Q'), ('\u{1d411}', 'R'), ('\u{1d412}', 'S'),
+    ('\u{1d413}', 'T'), ('\u{1d414}', 'U'), ('\u{1d415}', 'V'), ('\u{1d416}', 'W'),
+    ('\u{1d417}', 'X'), ('\u{1d418}', 'Y'), ('\u{1d419}', 'Z'), ('\u{1d41a}', 'a'),
+    ('\u{1d41b}', 'b'), ('\u{1d41c}', 'c'), ('\u{1d41d}', 'd'), ('\u{1d41e}', 'e'),
+    ('\u{1d41f}', 'f'), ('\u{1d420}', 'g'), ('\u{1d421}', 'h'), ('\u{1d422}', 'i'),
+    ('\u{1d423}', 'j'), ('\u{1d424}', 'k'), ('\u{1d425}', 'l'), ('\u{1d427}', 'n'),
+    ('\u{1d428}', 'o'), ('\u{1d429}', 'p'), ('\u{1d42a}', 'q'), ('\u{1d42b}', 'r'),
+    ('\u{1d42c}', 's'), ('\u{1d42d}', 't'), ('\u{1d42e}', 'u'), ('\u{1d42f}', 'v'),
+    ('\u{1d430}', 'w'), ('\u{1d431}', 'x'), ('\u{1d432}', 'y'), ('\u{1d433}', 'z'),
+    ('\u{1d434}', 'A'), ('\u{1d435}', 'B'), ('\u{1d436}', 'C'), ('\u{1d437}', 'D'),
+    ('\u{1d438}', 'E'), ('\u{1d439}', 'F'), ('\u{1d43a}', 'G'), ('\u{1d43b}', 'H'),
+    ('\u{1d43c}', 'l'), ('\u{1d43d}', 'J'), ('\u{1d43e}', 'K'), ('\u{1d43f}', 'L'),
+    ('\u{1d440}', 'M'), ('\u{1d441}', 'N'), ('\u{1d442}', 'O'), ('\u{1d443}', 'P'),
+    ('\u{1d444}', 'Q'), ('\u{1d445}', 'R'), ('\u{1d446}', 'S'), ('\u{1d447}', 'T'),
+    ('\u{1d448}', 'U'), ('\u{1d449}', 'V'), ('\u{1d44a}', 'W'), ('\u{1d44b}', 'X'),
+    ('\u{1d44c}', 'Y'), ('\u{1d44d}', 'Z'), ('\u{1d44e}', 'a'), ('\u{1d44f}', 'b'),
+    ('\u{1d450}', 'c'), ('\u{1d451}', 'd'), ('\u{1d452}', 'e'), ('\u{1d453}', 'f'),
+    ('\u{1d454}', 'g'), ('\u{1d456}', 'i'), ('\u{1d457}', 'j'), ('\u{1d458}', 'k'),
+    ('\u{1d459}', 'l'), ('\u{1d45b}', 'n'), ('\u{1d45c}', 'o'), ('\u{1d45d}', 'p'),
+    ('\u{1d45e}', 'q'), ('\u{1d45f}', 'r'), ('\u{1d460}', 's'), ('\u{1d461}', 't'),
+    ('\u{1d462}', 'u'), ('\u{1d463}', 'v'), ('\u{1d464}', 'w'), ('\u{1d465}', 'x'),
+    ('\u{1d466}', 'y'), ('\u{1d467}', 'z'), ('\u{1d468}', 'A'), ('\u{1d469}', 'B'),
+    ('\u{1d46a}', 'C'), ('\u{1d46b}', 'D'), ('\u{1d46c}', 'E'), ('\u{1d46d}', 'F'),
+    ('\u{1d46e}', 'G'), ('\u{1d46f}', 'H'), ('\u{1d470}', 'l'), ('\u{1d471}', 'J'),
+    ('\u{1d472}', 'K'), ('\u{1d473}', 'L'), ('\u{1d474}', 'M'), ('\u{1d475}', 'N'),
+    ('\u{1d476}', 'O'), ('\u{1d477}', 'P'), ('\u{1d478}', 'Q'), ('\u{1d479}', 'R'),
+    ('\u{1d47a}', 'S'), ('\u{1d47b}', 'T'), ('\u{1d47c}', 'U'), ('\u{1d47d}', 'V'),
+    ('\u{1d47e}', 'W'), ('\u{1d47f}', 'X'), ('\u{1d480}', 'Y'), ('\u{1d481}', 'Z'),
+    ('\u{1d482}', 'a'), ('\u{1d483}', 'b'), ('\u{1d484}', 'c'), ('\u{1d485}', 'd'),
+    ('\u{1d486}', 'e'), ('\u{1d487}', 'f'), ('\u{1d488}', 'g'), ('\u{1d489}', 'h'),
+    ('\u{1d48a}', 'i'), ('\u{1d48b}', 'j'), ('\u{1d48c}', 'k'), ('\u{1d48d}', 'l'),
+    ('\u{1d48f}', 'n'), ('\u{1d490}', 'o'), ('\u{1d491}', 'p'), ('\u{1d492}', 'q'),
+    ('\u{1d493}', 'r'), ('\u{1d494}', 's'), ('\u{1d495}', 't'), ('\u{1d496}', 'u'),
+    ('\u{1d497}', 'v'), ('\u{1d498}', 'w'), ('\u{1d499}', 'x'), ('\u{1d49a}', 'y'),
+    ('\u{1d49b}', 'z'), ('\u{1d49c}', 'A'), ('\u{1d49e}', 'C'), ('\u{1d49f}', 'D'),
+    ('\u{1d4a2}', 'G'), ('\u{1d4a5}', 'J'), ('\u{1d4a6}', 'K'), ('\u{1d4a9}', 'N'),
+    ('\u{1d4aa}', 'O'), ('\u{1d4ab}', 'P'), ('\u{1d4ac}', 'Q'), ('\u{1d4ae}', 'S'),
+    ('\u{1d4af}', 'T'), ('\u{1d4b0}', 'U'), ('\u{1d4b1}', 'V'), ('\u{1d4b2}', 'W'),
+    ('\u{1d4b3}', 'X'), ('\u{1d4b4}', 'Y'), ('\u{1d4b5}', 'Z'), ('\u{1d4b6}', 'a'),
+    ('\u{1d4b7}', 'b'), ('\u{1d4b8}', 'c'), ('\u{1d4b9}', 'd'), ('\u{1d4bb}', 'f'),
+    ('\u{1d4bd}', 'h'), ('\u{1d4be}', 'i'), ('\u{1d4bf}', 'j'), ('\u{1d4c0}', 'k'),
+    ('\u{1d4c1}', 'l'), ('\u{1d4c3}', 'n'), ('\u{1d4c5}', 'p'), ('\u{1d4c6}', 'q'),
+    ('\u{1d4c7}', 'r'), ('\u{1d4c8}', 's'), ('\u{1d4c9}', 't'), ('\u{1d4ca}', 'u'),
+    ('\u{1d4cb}', 'v'), ('\u{1d4cc}', 'w'), ('\u{1d4cd}', 'x'), ('\u{1d4ce}', 'y'),
+    ('\u{1d4cf}', 'z'), ('\u{1d4d0}', 'A'), ('\u{1d4d1}', 'B'), ('\u{1d4d2}', 'C'),
+    ('\u{1d4d3}', 'D'), ('\u{1d4d4}', 'E'), ('\u{1d4d5}', 'F'), ('\u{1d4d6}', 'G'),
+    ('\u{1d4d7}', 'H'), ('\u{1d4d8}', 'l'), ('\u{1d4d9}', 'J'), ('\u{1d4da}', 'K'),
+    ('\u{1d4db}', 'L'), ('\u{1d4dc}', 'M'), ('\u{1d4dd}', 'N'), ('\u{1d4de}', 'O'),
+    ('\u{1d4df}', 'P'), ('\u{1d4e0}', 'Q'), ('\u{1d4e1}', 'R'), ('\u{1d4e2}', 'S'),
+    ('\u{1d4e3}', 'T'), ('\u{1d4e4}', 'U'), ('\u{1d4e5}', 'V'), ('\u{1d4e6}', 'W'),
+    ('\u{1d4e7}', 'X'), ('\u{1d4e8}', 'Y'), ('\u{1d4e9}', 'Z'), ('\u{1d4ea}', 'a'),
+    ('\u{1d4eb}', 'b'), ('\u{1d4ec}', 'c'), ('\u{1d4ed}', 'd'), ('\u{1d4ee}', 'e'),
+    ('\u{1d4ef}', 'f'), ('\u{1d4f0}', 'g'), ('\u{1d4f1}', 'h'), ('\u{1d4f2}', 'i'),
+    ('\u{1d4f3}', 'j'), ('\u{1d4f4}', 'k'), ('\u{1d4f5}', 'l'), ('\u{1d4f7}', 'n'),
+    ('\u{1d4f8}', 'o'), ('\u{1d4f9}', 'p'), ('\u{1d4fa}', 'q'), ('\u{1d4fb}', 'r'),
+    ('\u{1d4fc}', 's'), ('\u{1d4fd}', 't'), ('\u{1d4fe}', 'u'), ('\u{1d4ff}', 'v'),
+    ('\u{1d500}', 'w'), ('\u{1d501}', 'x'), ('\u{1d502}', 'y'), ('\u{1d503}', 'z'),
+    ('\u{1d504}', 'A'), ('\u{1d505}', 'B'), ('\u{1d507}', 'D'), ('\u{1d508}', 'E'),
+    ('\u{1d509}', 'F'), ('\u{1d50a}', 'G'), ('\u{1d50d}', 'J'), ('\u{1d50e}', 'K'),
+    ('\u{1d50f}', 'L'), ('\u{1d510}', 'M'), ('\u{1d511}', 'N'), ('\u{1d512}', 'O'),
+    ('\u{1d513}', 'P'), ('\u{1d514}', 'Q'), ('\u{1d516}', 'S'), ('\u{1d517}', 'T'),
+    ('\u{1d518}', 'U'), ('\u{1d519}', 'V'), ('\u{1d51a}', 'W'), ('\u{1d51b}', 'X'),
+    ('\u{1d51c}', 'Y'), ('\u{1d51e}', 'a'), ('\u{1d51f}', 'b'), ('\u{1d520}', 'c'),
+    ('\u{1d521}', 'd'), ('\u{1d522}', 'e'), ('\u{1d523}', 'f'), ('\u{1d524}', 'g'),
+    ('\u{1d525}', 'h'), ('\u{1d526}', 'i'), ('\u{1d527}', 'j'), ('\u{1d528}', 'k'),
+    ('\u{1d529}', 'l'), ('\u{1d52b}', 'n'), ('\u{1d52c}', 'o'), ('\u{1d52d}', 'p'),
+    ('\u{1d52e}', 'q'), ('\u{1d52f}', 'r'), ('\u{1d530}', 's'), ('\u{1d531}', 't'),
+    ('\u{1d532}', 'u'), ('\u{1d533}', 'v'), ('\u{1d534}', 'w'), ('\u{1d535}', 'x'),
+    ('\u{1d536}', 'y'), ('\u{1d537}', 'z'), ('\u{1d538}', 'A'), ('\u{1d539}', 'B'),
+    ('\u{1d53b}', 'D'), ('\u{1d53c}', 'E'), ('\u{1d53d}', 'F'), ('\u{1d53e}', 'G'),
+    ('\u{1d540}', 'l'), ('\u{1d541}', 'J'), ('\u{1d542}', 'K'), ('\u{1d543}', 'L'),
+    ('\u{1d544}', 'M'), ('\u{1d546}', 'O'), ('\u{1d54a}', 'S'), ('\u{1d54b}', 'T'),
+    ('\u{1d54c}', 'U'), ('\u{1d54d}', 'V'), ('\u{1d54e}', 'W'), ('\u{1d54f}', 'X'),
+    ('\u{1d550}', 'Y'), ('\u{1d552}', 'a'), ('\u{1d553}', 'b'), ('\u{1d554}', 'c'),
+    ('\u{1d555}', 'd'), ('\u{1d556}', 'e'), ('\u{1d557}', 'f'), ('\u{1d558}', 'g'),
+    ('\u{1d559}', 'h'), ('\u{1d55a}', 'i'), ('\u{1d55b}', 'j'), ('\u{1d55c}', 'k'),
+    ('\u{1d55d}', 'l'), ('\u{1d55f}', 'n'), ('\u{1d560}', 'o'), ('\u{1d561}', 'p'),
+    ('\u{1d562}', 'q'), ('\u{1d563}', 'r'), ('\u{1d564}', 's'), ('\u{1d565}', 't'),
+    ('\u{1d566}', 'u'), ('\u{1d567}', 'v'), ('\u{1d568}', 'w'), ('\u{1d569}', 'x'),
+    ('\u{1d56a}', 'y'), ('\u{1d56b}', 'z'), ('\u{1d56c}', 'A'), ('\u{1d56d}', 'B'),
+    ('\u{1d56e}', 'C'), ('\u{1d56f}', 'D'), ('\u{1d570}', 'E'), ('\u{1d571}', 'F'),
+    ('\u{1d572}', 'G'), ('\u{1d573}', 'H'), ('\u{1d574}', 'l'), ('\u{1d575}', 'J'),
+    ('\u{1d576}', 'K'), ('\u{1d577}', 'L'), ('\u{1d578}', 'M'), ('\u{1d579}', 'N'),
+    ('\u{1d57a}', 'O'), ('\u{1d57b}', 'P'), ('\u{1d57c}', 'Q'), ('\u{1d57d}', 'R'),
+    ('\u{1d57e}', 'S'), ('\u{1d57f}', 'T'), ('\u{1d580}', 'U'), ('\u{1d581}', 'V'),
+    ('\u{1d582}', 'W'), ('\u{1d583}', 'X'), ('\u{1d584}', 'Y'), ('\u{1d585}', 'Z'),
+    ('\u{1d586}', 'a'), ('\u{1d587}', 'b'), ('\u{1d588}', 'c'), ('\u{1d589}', 'd'),
+    ('\u{1d58a}', 'e'), ('\u{1d58b}', 'f'), ('\u{1d58c}', 'g'), ('\u{1d58d}', 'h'),
+    ('\u{1d58e}', 'i'), ('\u{1d58f}', 'j'), ('\u{1d590}', 'k'), ('\u{1d591}', 'l'),
+    ('\u{1d593}', 'n'), ('\u{1d594}', 'o'), ('\u{1d595}', 'p'), ('\u{1d596}', 'q'),
+    ('\u{1d597}', 'r'), ('\u{1d598}', 's'), ('\u{1d599}', 't'), ('\u{1d59a}', 'u'),
+    ('\u{1d59b}', 'v'), ('\u{1d59c}', 'w'), ('\u{1d59d}', 'x'), ('\u{1d59e}', 'y'),
+    ('\u{1d59f}', 'z'), ('\u{1d5a0}', 'A'), ('\u{1d5a1}', 'B'), ('\u{1d5a2}', 'C'),
+    ('\u{1d5a3}', 'D'), ('\u{1d5a4}', 'E'), ('\u{1d5a5}', 'F'), ('\u{1d5a6}', 'G'),
+    ('\u{1d5a7}', 'H'), ('\u{1d5a8}', 'l'), ('\u{1d5a9}', 'J'), ('\u{1d5aa}', 'K'),
+    ('\u{1d5ab}', 'L'), ('\u{1d5ac}', 'M'), ('\u{1d5ad}', 'N'), ('\u{1d5ae}', 'O'),
+    ('\u{1d5af}', 'P'), ('\u{1d5b0}', 'Q'), ('\u{1d5b1}', 'R'), ('\u{1d5b2}', 'S'),
+    ('\u{1d5b3}', 'T'), ('\u{1d5b4}', 'U'), ('\u{1d5b5}', 'V'), ('\u{1d5b6}', 'W'),
+    ('\u{1d5b7}', 'X'), ('\u{1d5b8}', 'Y'), ('\u{1d5b9}', 'Z'), ('\u{1d5ba}', 'a'),
+    ('\u{1d5bb}', 'b'), ('\u{1d5bc}', 'c'), ('\u{1d5bd}', 'd'), ('\u{1d5be}', 'e'),
+    ('\u{1d5bf}', 'f'), ('\u{1d5c0}', 'g'), ('\u{1d5c1}', 'h'), ('\u{1d5c2}', 'i'),
+    ('\u{1d5c3}', 'j'), ('\u{1d5c4}', 'k'), ('\u{1d5c5}', 'l'), ('\u{1d5c7}', 'n'),
+    ('\u{1d5c8}', 'o'), ('\u{1d5c9}', 'p'), ('\u{1d5ca}', 'q'), ('\u{1d5cb}', 'r'),
+    ('\u{1d5cc}', 's'), ('\u{1d5cd}', 't'), ('\u{1d5ce}', 'u'), ('\u{1d5cf}', 'v'),
+    ('\u{1d5d0}', 'w'), ('\u{1d5d1}', 'x'), ('\u{1d5d2}', 'y'), ('\u{1d5d3}', 'z'),
+    ('\u{1d5d4}', 'A'), ('\u{1d5d5}', 'B'), ('\u{1d5d6}', 'C'), ('\u{1d5d7}', 'D'),
+    ('\u{1d5d8}', 'E'), ('\u{1d5d9}', 'F'), ('\u{1d5da}', 'G'), ('\u{1d5db}', 'H'),
+    ('\u{1d5dc}', 'l'), ('\u{1d5dd}', 'J'), ('\u{1d5de}', 'K'), ('\u{1d5df}', 'L'),
+    ('\u{1d5e0}', 'M'), ('\u{1d5e1}', 'N'), ('\u{1d5e2}', 'O'), ('\u{1d5e3}', 'P'),
+    ('\u{1d5e4}', 'Q'), ('\u{1d5e5}', 'R'), ('\u{1d5e6}', 'S'), ('\u{1d5e7}', 'T'),
+    ('\u{1d5e8}', 'U'), ('\u{1d5e9}', 'V'), ('\u{1d5ea}', 'W'), ('\u{1d5eb}', 'X'),
+    ('\u{1d5ec}', 'Y'), ('\u{1d5ed}', 'Z'), ('\u{1d5ee}', 'a'), ('\u{1d5ef}', 'b'),
+    ('\u{1d5f0}', 'c'), ('\u{1d5f1}', 'd'), ('\u{1d5f2}', 'e'), ('\u{1d5f3}', 'f'),
+    ('\u{1d5f4}', 'g'), ('\u{1d5f5}', 'h'), ('\u{1d5f6}', 'i'), ('\u{1d5f7}', 'j'),
+    ('\u{1d5f8}', 'k'), ('\u{1d5f9}', 'l'), ('\u{1d5fb}', 'n'), ('\u{1d5fc}', 'o'),
+    ('\u{1d5fd}', 'p'), ('\u{1d5fe}', 'q'), ('\u{1d5ff}', 'r'), ('\u{1d600}', 's'),
+    ('\u{1d601}', 't'), ('\u{1d602}', 'u'), ('\u{1d603}', 'v'), ('\u{1d604}', 'w'),
+    ('\u{1d605}', 'x'), ('\u{1d606}', 'y'), ('\u{1d607}', 'z'), ('\u{1d608}', 'A'),
+    ('\u{1d609}', 'B'), ('\u{1d60a}', 'C'), ('\u{1d60b}', 'D'), ('\u{1d60c}', 'E'),
+    ('\u{1d60d}', 'F'), ('\u{1d60e}', 'G'), ('\u{1d60f}', 'H'), ('\u{1d610}', 'l'),
+    ('\u{1d611}', 'J'), ('\u{1d612}', 'K'), ('\u{1d613}', 'L'), ('\u{1d614}', 'M'),
+    ('\u{1d615}', 'N'), ('\u{1d616}', 'O'), ('\u{1d617}', 'P'), ('\u{1d618}', 'Q'),
+    ('\u{1d619}', 'R'), ('\u{1d61a}', 'S'), ('\u{1d61b}', 'T'), ('\u{1d61c}', 'U'),
+    ('\u{1d61d}', 'V'), ('\u{1d61e}', 'W'), ('\u{1d61f}', 'X'), ('\u{1d620}', 'Y'),
+    ('\u{1d621}', 'Z'), ('\u{1d622}', 'a'), ('\u{1d623}', 'b'), ('\u{1d624}', 'c'),
+    ('\u{1d625}', 'd'), ('\u{1d626}', 'e'), ('\u{1d627}', 'f'), ('\u{1d628}', 'g'),
+    ('\u{1d629}', 'h'), ('\u{1d62a}', 'i'), ('\u{1d62b}', 'j'), ('\u{1d62c}', 'k'),
+    ('\u{1d62d}', 'l'), ('\u{1d62f}', 'n'), ('\u{1d630}', 'o'), ('\u{1d631}', 'p'),
+    ('\u{1d632}', 'q'), ('\u{1d633}', 'r'), ('\u{1d634}', 's'), ('\u{1d635}', 't'),
+    ('\u{1d636}', 'u'), ('\u{1d637}', 'v'), ('\u{1d638}', 'w'), ('\u{1d639}', 'x'),
+    ('\u{1d63a}', 'y'), ('\u{1d63b}', 'z'), ('\u{1d63c}', 'A'), ('\u{1d63d}', 'B'),
+    ('\u{1d63e}', 'C'), ('\u{1d63f}', 'D'), ('\u{1d640}', 'E'), ('\u{1d641}', 'F'),
+    ('\u{1d642}', 'G'), ('\u{1d643}', 'H'), ('\u{1d644}', 'l'), ('\u{1d645}', 'J'),
+    ('\u{1d646}', 'K'), ('\u{1d647}', 'L'), ('\u{1d648}', 'M'), ('\u{1d649}', 'N'),
+    ('\u{1d64a}', 'O'), ('\u{1d64b}', 'P'), ('\u{1d64c}', 'Q'), ('\u{1d64d}', 'R'),
+    ('\u{1d64e}', 'S'), ('\u{1d64f}', 'T'), ('\u{1d650}', 'U'), ('\u{1d651}', 'V'),
+    ('\u{1d652}', 'W'), ('\u{1d653}', 'X'), ('\u{1d654}', 'Y'), ('\u{1d655}', 'Z'),
+    ('\u{1d656}', 'a'), ('\u{1d657}', 'b'), ('\u{1d658}', 'c'), ('\u{1d659}', 'd'),
+    ('\u{1d65a}', 'e'), ('\u{1d65b}', 'f'), ('\u{1d65c}', 'g'), ('\u{1d65d}', 'h'),
+    ('\u{1d65e}', 'i'), ('\u{1d65f}', 'j'), ('\u{1d660}', 'k'), ('\u{1d661}', 'l'),
+    ('\u{1d663}', 'n'), ('\u{1d664}', 'o'), ('\u{1d665}', 'p'), ('\u{1d666}', 'q'),
+    ('\u{1d667}', 'r'), ('\u{1d668}', 's'), ('\u{1d669}', 't'), ('\u{1d66a}', 'u'),
+    ('\u{1d66b}', 'v'), ('\u{1d66c}', 'w'), ('\u{1d66d}', 'x'), ('\u{1d66e}', 'y'),
+    ('\u{1d66f}', 'z'), ('\u{1d670}', 'A'), ('\u{1d671}', 'B'), ('\u{1d672}', 'C'),
+    ('\u{1d673}', 'D'), ('\u{1d674}', 'E'), ('\u{1d675}', 'F'), ('\u{1d676}', 'G'),
+    ('\u{1d677}', 'H'), ('\u{1d678}', 'l'), ('\u{1d679}', 'J'), ('\u{1d67a}', 'K'),
+    ('\u{1d67b}', 'L'), ('\u{1d67c}', 'M'), ('\u{1d67d}', 'N'), ('\u{1d67e}', 'O'),
+    ('\u{1d67f}', 'P'), ('\u{1d680}', 'Q'), ('\u{1d681}', 'R'), ('\u{1d682}', 'S'),
+    ('\u{1d683}', 'T'), ('\u{1d684}', 'U'), ('\u{1d685}', 'V'), ('\u{1d686}', 'W'),
+    ('\u{1d687}', 'X'), ('\u{1d688}', 'Y'), ('\u{1d689}', 'Z'), ('\u{1d68a}', 'a'),
+    ('\u{1d68b}', 'b'), ('\u{1d68c}', 'c'), ('\u{1d68d}', 'd'), ('\u{1d68e}', 'e'),
+    ('\u{1d68f}', 'f'), ('\u{1d690}', 'g'), ('\u{1d691}', 'h'), ('\u{1d692}', 'i'),
+    ('\u{1d693}', 'j'), ('\u{1d694}', 'k'), ('\u{1d695}', 'l'), ('\u{1d697}', 'n'),
+    ('\u{1d698}', 'o'), ('\u{1d699}', 'p'), ('\u{1d69a}', 'q'), ('\u{1d69b}', 'r'),
+    ('\u{1d69c}', 's'), ('\u{1d69d}', 't'), ('\u{1d69e}', 'u'), ('\u{1d69f}', 'v'),
+    ('\u{1d6a0}', 'w'), ('\u{1d6a1}', 'x'), ('\u{1d6a2}', 'y'), ('\u{1d6a3}', 'z'),
+    ('\u{1d6a4}', 'i'), ('\u{1d6a8}', 'A'), ('\u{1d6a9}', 'B'), ('\u{1d6ac}', 'E'),
+    ('\u{1d6ad}', 'Z'), ('\u{1d6ae}', 'H'), ('\u{1d6b0}', 'l'), ('\u{1d6b1}', 'K'),
+    ('\u{1d6b3}', 'M'), ('\u{1d6b4}', 'N'), ('\u{1d6b6}', 'O'), ('\u{1d6b8}', 'P'),
+    ('\u{1d6bb}', 'T'), ('\u{1d6bc}', 'Y'), ('\u{1d6be}', 'X'), ('\u{1d6c2}', 'a'),
+    ('\u{1d6c4}', 'y'), ('\u{1d6ca}', 'i'), ('\u{1d6ce}', 'v'), ('\u{1d6d0}', 'o'),
+    ('\u{1d6d2}', 'p'), ('\u{1d6d4}', 'o'), ('\u{1d6d6}', 'u'), ('\u{1d6e0}', 'p'),
+    ('\u{1d6e2}', 'A'), ('\u{1d6e3}', 'B'), ('\u{1d6e6}', 'E'), ('\u{1d6e7}', 'Z'),
+    ('\u{1d6e8}', 'H'), ('\u{1d6ea}', 'l'), ('\u{1d6eb}', 'K'), ('\u{1d6ed}', 'M'),
+    ('\u{1d6ee}', 'N'), ('\u{1d6f0}', 'O'), ('\u{1d6f2}', 'P'), ('\u{1d6f5}', 'T'),
+    ('\u{1d6f6}', 'Y'), ('\u{1d6f8}', 'X'), ('\u{1d6fc}', 'a'), ('\u{1d6fe}', 'y'),
+    ('\u{1d704}', 'i'), ('\u{1d708}', 'v'), ('\u{1d70a}', 'o'), ('\u{1d70c}', 'p'),
+    ('\u{1d70e}', 'o'), ('\u{1d710}', 'u'), ('\u{1d71a}', 'p'), ('\u{1d71c}', 'A'),
+    ('\u{1d71d}', 'B'), ('\u{1d720}', 'E'), ('\u{1d721}', 'Z'), ('\u{1d722}', 'H'),
+    ('\u{1d724}', 'l'), ('\u{1d725}', 'K'), ('\u{1d727}', 'M'), ('\u{1d728}', 'N'),
+    ('\u{1d72a}', 'O'), ('\u{1d72c}', 'P'), ('\u{1d72f}', 'T'), ('\u{1d730}', 'Y'),
+    ('\u{1d732}', 'X'), ('\u{1d736}', 'a'), ('\u{1d738}', 'y'), ('\u{1d73e}', 'i'),
+    ('\u{1d742}', 'v'), ('\u{1d744}', 'o'), ('\u{1d746}', 'p'), ('\u{1d748}', 'o'),
+    ('\u{1d74a}', 'u'), ('\u{1d754}', 'p'), ('\u{1d756}', 'A'), ('\u{1d757}', 'B'),
+    ('\u{1d75a}', 'E'), ('\u{1d75b}', 'Z'), ('\u{1d75c}', 'H'), ('\u{1d75e}', 'l'),
+    ('\u{1d75f}', 'K'), ('\u{1d761}', 'M'), ('\u{1d762}', 'N'), ('\u{1d764}', 'O'),
+    ('\u{1d766}', 'P'), ('\u{1d769}', 'T'), ('\u{1d76a}', 'Y'), ('\u{1d76c}', 'X'),
+    ('\u{1d770}', 'a'), ('\u{1d772}', 'y'), ('\u{1d778}', 'i'), ('\u{1d77c}', 'v'),
+    ('\u{1d77e}', 'o'), ('\u{1d780}', 'p'), ('\u{1d782}', 'o'), ('\u{1d784}', 'u'),
+    ('\u{1d78e}', 'p'), ('\u{1d790}', 'A'), ('\u{1d791}', 'B'), ('\u{1d794}', 'E'),
+    ('\u{1d795}', 'Z'), ('\u{1d796}', 'H'), ('\u{1d798}', 'l'), ('\u{1d799}', 'K'),
+    ('\u{1d79b}', 'M'), ('\u{1d79c}', 'N'), ('\u{1d79e}', 'O'), ('\u{1d7a0}', 'P'),
+    ('\u{1d7a3}', 'T'), ('\u{1d7a4}', 'Y'), ('\u{1d7a6}', 'X'), ('\u{1d7aa}', 'a'),
+    ('\u{1d7ac}', 'y'), ('\u{1d7b2}', 'i'), ('\u{1d7b6}', 'v'), ('\u{1d7b8}', 'o'),
+    ('\u{1d7ba}', 'p'), ('\u{1d7bc}', 'o'), ('\u{1d7be}', 'u'), ('\u{1d7c8}', 'p'),
+    ('\u{1d7ca}', 'F'), ('\u{1d7ce}', 'O'), ('\u{1d7cf}', 'l'), ('\u{1d7d0}', '2'),
+    ('\u{1d7d1}', '3'), ('\u{1d7d2}', '4'), ('\u{1d7d3}', '5'), ('\u{1d7d4}', '6'),
+    ('\u{1d7d5}', '7'), ('\u{1d7d6}', '8'), ('\u{1d7d7}', '9'), ('\u{1d7d8}', 'O'),
+    ('\u{1d7d9}', 'l'), ('\u{1d7da}', '2'), ('\u{1d7db}', '3'), ('\u{1d7dc}', '4'),
+    ('\u{1d7dd}', '5'), ('\u{1d7de}', '6'), ('\u{1d7df}', '7'), ('\u{1d7e0}', '8'),
+    ('\u{1d7e1}', '9'), ('\u{1d7e2}', 'O'), ('\u{1d7e3}', 'l'), ('\u{1d7e4}', '2'),
+    ('\u{1d7e5}', '3'), ('\u{1d7e6}', '4'), ('\u{1d7e7}', '5'), ('\u{1d7e8}', '6'),
+    ('\u{1d7e9}', '7'), ('\u{1d7ea}', '8'), ('\u{1d7eb}', '9'), ('\u{1d7ec}', 'O'),
+    ('\u{1d7ed}', 'l'), ('\u{1d7ee}', '2'), ('\u{1d7ef}', '3'), ('\u{1d7f0}', '4'),
+    ('\u{1d7f1}', '5'), ('\u{1d7f2}', '6'), ('\u{1d7f3}', '7'), ('\u{1d7f4}', '8'),
+    ('\u{1d7f5}', '9'), ('\u{1d7f6}', 'O'), ('\u{1d7f7}', 'l'), ('\u{1d7f8}', '2'),
+    ('\u{1d7f9}', '3'), ('\u{1d7fa}', '4'), ('\u{1d7fb}', '5'), ('\u{1d7fc}', '6'),
+    ('\u{1d7fd}', '7'), ('\u{1d7fe}', '8'), ('\u{1d7ff}', '9'), ('\u{1e8c7}', 'l'),
+    ('\u{1e8cb}', '8'), ('\u{1ee00}', 'l'), ('\u{1ee24}', 'o'), ('\u{1ee64}', 'o'),
+    ('\u{1ee80}', 'l'), ('\u{1ee84}', 'o'), ('\u{1f74c}', 'C'), ('\u{1f768}', 'T'),
+    ('\u{1fbf0}', 'O'), ('\u{1fbf1}', 'l'), ('\u{1fbf2}', '2'), ('\u{1fbf3}', '3'),
+    ('\u{1fbf4}', '4'), ('\u{1fbf5}', '5'), ('\u{1fbf6}', '6'), ('\u{1fbf7}', '7'),
+    ('\u{1fbf8}', '8'), ('\u{1fbf9}', '9'),
+];
@@ -0,0 +1,159 @@
+//! Converting the contents of a literal to another kind of literal.
+
+use core::ops::Range;
+
+use crate::escape::{escape_unit, unescape_units, EscapeStyle, Unit};
+use crate::{EscapeError, Mode};
+
+/// Convert the contents of a literal to another kind of literal
+///
+/// Takes the contents of a literal of kind `from` (without quotes)
+/// and produces the contents of a literal of kind `to` with the same value
+/// as a sequence of pieces, which are returned by invoking `emit`.
+/// Returns the number of `#`s delimiting the new literal if it is raw,
+/// and 0 otherwise.
+///
+/// Values of char and string literals are compared as chars, values of byte,
+/// byte string and C string literals as bytes (where a non-ASCII char in a
+/// C string stands for its UTF-8 encoding).
+///
+/// Fails without producing any output if `src` has errors, or if its value
+/// cannot be written in a literal of kind `to` (e.g. a non-ASCII char in a
+/// byte string, a nul in a C string or a carriage return in a raw string),
+/// returning the error with its range in `src`.
+pub fn convert(
+    src: &str,
+    from: Mode,
+    to: Mode,
+    mut emit: impl FnMut(&str),
+) -> Result<u8, (Range<usize>, EscapeError)> {
+    let mut hashes = RawHashes::default();
+    let mut n_units = 0;
+    let mut result = Ok(());
+    converted_units(src, from, to, |range, res| {
+        if result.is_ok() {
+            n_units += 1;
+            result = res
+                .and_then(|unit| {
+                    if !to.in_double_quotes() && n_units > 1 {
+                        return Err(EscapeError::MoreThanOneChar);
+                    }
+                    escape_unit(unit, to, &EscapeStyle::default())?;
+                    match unit {
+                        Unit::Char(c) if to.is_raw() => hashes.push(c),
+                        _ => Ok(()),
+                    }
+                })
+                .map_err(|err| (range, err));
+        }
+    });
+    result?;
+    if !to.in_double_quotes() && n_units == 0 {
+        return Err((0..src.len(), EscapeError::ZeroChars));
+    }
+
+    converted_units(src, from, to, |_, res| {
+        emit(
+            escape_unit(res.unwrap(), to, &EscapeStyle::default())
+                .unwrap()
+                .as_str(),
+        );
+    });
+    Ok(hashes.min)
+}
+
+/// Unescape a literal into the units of a literal of kind `to`
+///
+/// Skips warnings, so that all units or errors can be escaped directly.
+fn converted_units(
+    src: &str,
+    from: Mode,
+    to: Mode,
+    mut callback: impl FnMut(Range<usize>, Result<Unit, EscapeError>),
+) {
+    let from_c_str = matches!(from, Mode::CStr | Mode::RawCStr);
+    let to_bytes = matches!(to, Mode::Byte | Mode::ByteStr | Mode::RawByteStr);
+    let mut utf8 = Utf8Decoder::default();
+    unescape_units(src, from, |range, res| match res {
+        Err(err) if !err.is_fatal() => {}
+        // non-ASCII chars in C strings stand for their UTF-8 encoding
+        Ok(Unit::Char(c)) if from_c_str && to_bytes && !c.is_ascii() => {
+            for b in c.encode_utf8(&mut [0; 4]).bytes() {
+                callback(range.clone(), Ok(Unit::Byte(b)));
+            }
+        }
+        // raw C strings can only contain high bytes as part of chars
+        Ok(Unit::Byte(b)) if to == Mode::RawCStr => utf8.push(range, b, &mut callback),
+        res => {
+            utf8.flush(&mut callback);
+            callback(range, res);
+        }
+    });
+    utf8.flush(&mut callback);
+}
+
+/// Groups high bytes into the chars they encode in UTF-8
+#[derive(Default)]
+struct Utf8Decoder {
+    buf: [u8; 4],
+    len: usize,
+    /// Range of the first byte in `buf`
+    first: Range<usize>,
+}
+
+impl Utf8Decoder {
+    fn push(
+        &mut self,
+        range: Range<usize>,
+        b: u8,
+        callback: &mut impl FnMut(Range<usize>, Result<Unit, EscapeError>),
+    ) {
+        if self.len == 0 {
+            self.first = range.clone();
+        }
+        self.buf[self.len] = b;
+        self.len += 1;
+        match core::str::from_utf8(&self.buf[..self.len]) {
+            Ok(s) => {
+                let c = s.chars().next().unwrap();
+                callback(self.first.start..range.end, Ok(Unit::Char(c)));
+                self.len = 0;
+            }
+            // incomplete, wait for the next byte
+            Err(e) if e.error_len().is_none() => {}
+            Err(_) => self.flush(callback),
+        }
+    }
+
+    /// Report an incomplete or invalid sequence by its first byte,
+    /// which cannot be written on its own anyway
+    fn flush(&mut self, callback: &mut impl FnMut(Range<usize>, Result<Unit, EscapeError>)) {
+        if self.len > 0 {
+            callback(self.first.clone(), Ok(Unit::Byte(self.buf[0])));
+            self.len = 0;
+        }
+    }
+}
+
+/// Computes the minimal number of `#`s around a raw literal, char by char
+#[derive(Default)]
+pub(crate) struct RawHashes {
+    /// Number of `#`s after the last `"`, if only `#`s followed it
+    run: Option<usize>,
+    pub(crate) min: u8,
+}
+
+impl RawHashes {
+    pub(crate) fn push(&mut self, c: char) -> Result<(), EscapeError> {
+        self.run = match (c, self.run) {
+            ('"', _) => Some(0),
+            ('#', Some(n)) => Some(n + 1),
+            _ => None,
+        };
+        if let Some(n) = self.run {
+            let needed = u8::try_from(n + 1).map_err(|_| EscapeError::TooManyHashes)?;
+            self.min = self.min.max(needed);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+//! Describing the escapes and chars in literals, e.g. for hovers and diagnostics.
+
+use core::num::NonZero;
+use core::ops::Range;
+use core::{fmt, iter};
+
+use crate::escape::{escape_unicode, unescape_units, EscapeStyle, Escaped, Unit};
+use crate::tokenize::single_quoted;
+use crate::{tokenize, MixedUnit, Mode, Piece, Unescape};
+
+/// Description of an escape, produced by [`describe_escape`]
+#[derive(Debug, PartialEq, Eq)]
+pub struct EscapeDescription {
+    /// Range of the escape in the contents
+    pub range: Range<usize>,
+    /// Kind of the escape, or the error if it is invalid
+    pub piece: Piece,
+    /// Value of the escape, if it is valid
+    pub value: Option<EscapeValue>,
+}
+
+/// Value of an escape
+///
+/// Displayed as a code point (`U+1F980`) or byte value (`0xA5`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeValue {
+    /// A char, in char, string and C string literals.
+    Char(char),
+    /// A byte, in byte and byte string literals, or a high byte in C string literals.
+    Byte(u8),
+}
+
+impl EscapeValue {
+    /// The unit of a C string literal with this value, if it can be in one
+    pub fn to_mixed_unit(self) -> Option<MixedUnit> {
+        match self {
+            EscapeValue::Char(c) => NonZero::new(c).map(MixedUnit::from),
+            EscapeValue::Byte(b) if b.is_ascii() => NonZero::new(b as char).map(MixedUnit::from),
+            EscapeValue::Byte(b) => NonZero::new(b).map(MixedUnit::from),
+        }
+    }
+
+    /// The bytes of the value: the UTF-8 encoding of a char, or a single byte
+    pub fn encode(self, buf: &mut [u8; 4]) -> &[u8] {
+        match self {
+            EscapeValue::Char(c) => c.encode_utf8(buf).as_bytes(),
+            EscapeValue::Byte(b) => {
+                buf[0] = b;
+                &buf[..1]
+            }
+        }
+    }
+
+    /// The Unicode name of a char, e.g. `CRAB` for `U+1F980`
+    ///
+    /// Control chars and bytes have no name.
+    #[cfg(feature = "unicode-names")]
+    pub fn name(self) -> Option<impl fmt::Display> {
+        match self {
+            EscapeValue::Char(c) => unicode_names2::name(c),
+            EscapeValue::Byte(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for EscapeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            EscapeValue::Char(c) => write!(f, "U+{:04X}", u32::from(c)),
+            EscapeValue::Byte(b) => write!(f, "0x{b:02X}"),
+        }
+    }
+}
+
+/// Describe the escape at a position in a literal
+///
+/// Takes the contents of a literal (without quotes) and a byte offset in them,
+/// and returns a description of the (possibly invalid) escape that contains
+/// the offset, or `None` if there is no escape there.
+pub fn describe_escape(src: &str, mode: Mode, offset: usize) -> Option<EscapeDescription> {
+    let mut found = None;
+    tokenize(src, mode, |range, piece| {
+        let is_escape = piece != Piece::LineContinuation && src[range.clone()].starts_with('\\');
+        if !mode.is_raw() && is_escape && range.contains(&offset) {
+            found = Some((range, piece));
+        }
+    });
+    let (range, piece) = found?;
+
+    // escapes stand on their own, even in char and byte literals
+    let mut value = None;
+    unescape_units(&src[range.clone()], mode, |_, res| {
+        value = match res {
+            Ok(Unit::Char(c)) if matches!(mode, Mode::Byte | Mode::ByteStr) => {
+                Some(EscapeValue::Byte(c as u8))
+            }
+            Ok(Unit::Char(c)) => Some(EscapeValue::Char(c)),
+            Ok(Unit::Byte(b)) => Some(EscapeValue::Byte(b)),
+            Err(_) => None,
+        }
+    });
+    Some(EscapeDescription {
+        range,
+        piece,
+        value,
+    })
+}
+
+/// Description of the contents of a char or byte literal with more than one
+/// char, produced by [`describe_more_than_one_char`]
+///
+/// A string literal with the same chars can be suggested with
+/// [`convert`](crate::convert), and the chars can be shown with [`escape_chars`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultipleChars {
+    /// Number of chars (or bytes) in the contents
+    pub chars: usize,
+    /// Whether the chars form a single extended grapheme cluster, which looks
+    /// like a single char, e.g. `e` followed by a combining accent or an emoji
+    /// ZWJ sequence.
+    ///
+    /// Always false without the `graphemes` feature.
+    pub single_grapheme: bool,
+}
+
+/// Describe the contents of a char or byte literal with more than one char
+///
+/// Takes the contents of a char or byte literal (without quotes), and returns
+/// a description if they are valid except for
+/// [`EscapeError::MoreThanOneChar`](crate::EscapeError::MoreThanOneChar),
+/// or `None` otherwise.
+pub fn describe_more_than_one_char(src: &str, mode: Mode) -> Option<MultipleChars> {
+    let mut chars = 0;
+    let mut valid = true;
+    match mode {
+        Mode::Char => single_quoted::<str>(src, &mut |_, res| {
+            chars += 1;
+            valid &= res.is_ok();
+        }),
+        Mode::Byte => single_quoted::<[u8]>(src, &mut |_, res| {
+            chars += 1;
+            valid &= res.is_ok();
+        }),
+        _ => return None,
+    }
+    if !valid || chars < 2 {
+        return None;
+    }
+    Some(MultipleChars {
+        chars,
+        single_grapheme: is_single_grapheme(src, mode),
+    })
+}
+
+/// Escape each char of the contents of a char or byte literal
+///
+/// Takes the contents of a char or byte literal (without quotes) that are
+/// valid except for possibly having more than one char, and produces a
+/// unicode escape for each char (or a hex escape for each byte), which are
+/// returned by invoking `emit`. Shows which chars something that looks like a
+/// single char is made of, e.g. `\u{65}` and `\u{301}` for a decomposed `é`.
+pub fn escape_chars(src: &str, mode: Mode, style: &EscapeStyle, mut emit: impl FnMut(&str)) {
+    for c in value_chars(src, mode) {
+        let escaped = match mode {
+            Mode::Byte => {
+                let mut out = Escaped::default();
+                style.write_hex(&mut out, c as u8).unwrap();
+                out
+            }
+            _ => escape_unicode(c, style),
+        };
+        emit(escaped.as_str());
+    }
+}
+
+/// The chars of the value of the contents of a char literal, or the bytes of
+/// those of a byte literal, skipping invalid escapes
+fn value_chars(src: &str, mode: Mode) -> impl Iterator<Item = char> + Clone + '_ {
+    let mut chars = src.chars();
+    iter::from_fn(move || loop {
+        let res = match chars.next()? {
+            '\\' if mode == Mode::Byte => <[u8]>::unescape_1(&mut chars).map(char::from),
+            '\\' => str::unescape_1(&mut chars),
+            c => Ok(c),
+        };
+        if let Ok(c) = res {
+            return Some(c);
+        }
+    })
+}
+
+/// Whether the value of the contents of a char or byte literal is a single
+/// extended grapheme cluster
+#[cfg(feature = "graphemes")]
+fn is_single_grapheme(src: &str, mode: Mode) -> bool {
+    use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete};
+
+    // feed the value to the cursor char by char
+    let len = value_chars(src, mode).map(char::len_utf8).sum();
+    let mut cursor = GraphemeCursor::new(0, len, true);
+    let mut chars = value_chars(src, mode);
+    let (mut buf, mut context_buf) = ([0; 4], [0; 4]);
+    let mut chunk_start = 0;
+    let Some(c) = chars.next() else {
+        return false;
+    };
+    let mut chunk: &str = c.encode_utf8(&mut buf);
+    loop {
+        match cursor.next_boundary(chunk, chunk_start) {
+            Ok(boundary) => return boundary == Some(len),
+            Err(GraphemeIncomplete::NextChunk) => {
+                let Some(c) = chars.next() else {
+                    return false;
+                };
+                chunk_start += chunk.len();
+                chunk = c.encode_utf8(&mut buf);
+            }
+            Err(GraphemeIncomplete::PreContext(end)) => {
+                let mut start = 0;
+                let Some(c) = value_chars(src, mode).find(|c| {
+                    start += c.len_utf8();
+                    start == end
+                }) else {
+                    return false;
+                };
+                cursor.provide_context(c.encode_utf8(&mut context_buf), end - c.len_utf8());
+            }
+            Err(_) => return false,
+        }
+    }
+}
+
+#[cfg(not(feature = "graphemes"))]
+fn is_single_grapheme(_: &str, _: Mode) -> bool {
+    false
+}
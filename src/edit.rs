@@ -0,0 +1,331 @@
+//! Editing the value of a literal without rewriting all of its contents.
+
+use core::ops::Range;
+
+use crate::convert::{convert, RawHashes};
+use crate::escape::{escape_unit, unescape_units, EscapeStyle, Unit};
+use crate::format::{escape_whitespace, first_error};
+use crate::{EscapeError, Mode};
+
+/// Errors that can occur when editing the value of a literal
+#[derive(Debug, PartialEq, Eq)]
+pub enum EditError {
+    /// The contents of the literal have an error, with its range in the contents.
+    Contents(Range<usize>, EscapeError),
+    /// The new value cannot be written in the literal, with the range of the
+    /// offending part of the replacement.
+    Replacement(Range<usize>, EscapeError),
+    /// The range is out of bounds of the value, or splits a unit of the value
+    /// (e.g. a char of a string or C string literal).
+    InvalidRange,
+}
+
+/// An edit of the contents of a literal, produced by [`edit_value`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueEdit {
+    /// Range of the contents that is replaced
+    pub range: Range<usize>,
+    /// Number of `#`s delimiting the edited literal if it is raw, and 0 otherwise
+    pub hashes: u8,
+}
+
+/// Replace part of the value of a literal
+///
+/// Takes the contents of a literal (without quotes), a range of its value and
+/// the text to put there, and produces the text that replaces
+/// [`ValueEdit::range`] of the contents as a sequence of pieces, which are
+/// returned by invoking `emit`.
+///
+/// Only the chars and escapes that make up the replaced part of the value are
+/// rewritten, and the replacement is escaped with `style`, so the rest of the
+/// contents keeps its escapes and string continuations. Whitespace directly
+/// after a string continuation, which it would skip, is escaped.
+///
+/// Positions in the value are byte offsets, where chars count as their UTF-8
+/// encoding (like in the value of a C string literal). In byte and byte string
+/// literals, the replacement also stands for its UTF-8 encoding.
+///
+/// Fails without producing any output if `src` has errors, if the range is
+/// invalid, or if the new value cannot be written in the literal (e.g. a
+/// non-ASCII char in a raw byte string or two chars in a char literal).
+pub fn edit_value(
+    src: &str,
+    mode: Mode,
+    value: Range<usize>,
+    replacement: &str,
+    style: &EscapeStyle,
+    mut emit: impl FnMut(&str),
+) -> Result<ValueEdit, EditError> {
+    first_error(src, mode).map_err(|(range, err)| EditError::Contents(range, err))?;
+    let (mut range, before) = locate(src, mode, value).ok_or(EditError::InvalidRange)?;
+    let after_continuation = before < range.start;
+
+    let mut result = Ok(());
+    replacement_units(replacement, mode, |unit_range, unit| {
+        if result.is_ok() {
+            result = escape_unit(unit, mode, style)
+                .map(drop)
+                .map_err(|err| EditError::Replacement(unit_range, err));
+        }
+    });
+    result?;
+
+    let whole = || 0..replacement.len();
+    let mut n_units = 0;
+    let mut hashes = RawHashes::default();
+    let mut result = Ok(());
+    new_units(src, mode, &range, replacement, |unit| {
+        n_units += 1;
+        match unit {
+            Unit::Char(c) if mode.is_raw() && result.is_ok() => result = hashes.push(c),
+            _ => {}
+        }
+    });
+    result.map_err(|err| EditError::Replacement(whole(), err))?;
+    if !mode.in_double_quotes() && n_units != 1 {
+        let err = if n_units == 0 {
+            EscapeError::ZeroChars
+        } else {
+            EscapeError::MoreThanOneChar
+        };
+        return Err(EditError::Replacement(whole(), err));
+    }
+
+    // Without a replacement, whitespace after the range would follow the
+    // continuation before it.
+    let next_whitespace = src[range.end..]
+        .chars()
+        .next()
+        .filter(|&c| after_continuation && replacement.is_empty() && is_skipped(c));
+    if let Some(c) = next_whitespace {
+        range.end += 1;
+        emit(escape_whitespace(Unit::Char(c), mode, style).as_str());
+    }
+    let mut first = true;
+    replacement_units(replacement, mode, |_, unit| {
+        let escaped = match unit {
+            Unit::Char(c) if first && after_continuation && is_skipped(c) => {
+                escape_whitespace(unit, mode, style)
+            }
+            unit => escape_unit(unit, mode, style).unwrap(),
+        };
+        first = false;
+        emit(escaped.as_str());
+    });
+    Ok(ValueEdit {
+        range,
+        hashes: hashes.min,
+    })
+}
+
+/// Split a literal at a position in its value
+///
+/// Takes the contents of a string, byte string or C string literal (raw or
+/// not) and a byte offset in its value (as for [`edit_value`]), and returns
+/// the contents of two literals of the same kind whose values are the parts of
+/// the value before and after `pos`. Both can be delimited like the original.
+///
+/// The contents are never cut inside an escape, and a string continuation at
+/// `pos` is dropped.
+///
+/// Fails if `src` has errors, or with [`EditError::InvalidRange`] if `pos` is
+/// out of bounds or inside a unit of the value, or if `mode` is a char or
+/// byte literal.
+pub fn split_at_value(src: &str, mode: Mode, pos: usize) -> Result<(&str, &str), EditError> {
+    first_error(src, mode).map_err(|(range, err)| EditError::Contents(range, err))?;
+    if !mode.in_double_quotes() {
+        return Err(EditError::InvalidRange);
+    }
+    let (range, before) = locate(src, mode, pos..pos).ok_or(EditError::InvalidRange)?;
+    Ok((&src[..before], &src[range.start..]))
+}
+
+/// Errors that can occur when joining two literals
+#[derive(Debug, PartialEq, Eq)]
+pub enum JoinError {
+    /// The contents of the first literal have an error, with its range in them.
+    First(Range<usize>, EscapeError),
+    /// The contents of the second literal have an error, with its range in them.
+    Second(Range<usize>, EscapeError),
+    /// The literals are of different kinds (other than raw and non-raw
+    /// literals of the same kind), or are char or byte literals.
+    IncompatibleModes,
+}
+
+/// Join two literals into one
+///
+/// Takes the contents of two string, byte string or C string literals of the
+/// same kind and produces the contents of a literal whose value is the
+/// concatenation of their values as a sequence of pieces, which are returned
+/// by invoking `emit`. Returns the kind of the joined literal and the number
+/// of `#`s delimiting it if it is raw, and 0 otherwise.
+///
+/// Literals of the same mode are joined as they are. If only one of them is
+/// raw, the joined literal is not raw and the contents of the raw one are
+/// escaped. Whitespace at the start of the second literal is escaped if the
+/// first ends with a string continuation, which would skip it.
+///
+/// Fails without producing any output if either literal has errors.
+pub fn join(
+    first: &str,
+    first_mode: Mode,
+    second: &str,
+    second_mode: Mode,
+    mut emit: impl FnMut(&str),
+) -> Result<(Mode, u8), JoinError> {
+    let mode = joined_mode(first_mode, second_mode).ok_or(JoinError::IncompatibleModes)?;
+    first_error(first, first_mode).map_err(|(range, err)| JoinError::First(range, err))?;
+    first_error(second, second_mode).map_err(|(range, err)| JoinError::Second(range, err))?;
+
+    let mut hashes = RawHashes::default();
+    if mode.is_raw() {
+        // raw contents are their value
+        for c in first.chars() {
+            hashes
+                .push(c)
+                .map_err(|err| JoinError::First(0..first.len(), err))?;
+        }
+        for c in second.chars() {
+            hashes
+                .push(c)
+                .map_err(|err| JoinError::Second(0..second.len(), err))?;
+        }
+    }
+
+    let mut after_continuation = false;
+    if first_mode == mode {
+        let mut end = 0;
+        unescape_units(first, first_mode, |range, res| {
+            if res.is_ok() {
+                end = range.end;
+            }
+        });
+        after_continuation = end < first.len();
+        emit(first);
+    } else {
+        // a raw literal, which can always be written without being raw
+        convert(first, first_mode, mode, &mut emit).unwrap();
+    }
+    let mut emit_second = |piece: &str| {
+        let first_char = piece.chars().next();
+        match first_char.filter(|&c| after_continuation && is_skipped(c)) {
+            Some(c) => {
+                let style = EscapeStyle::default();
+                emit(escape_whitespace(Unit::Char(c), mode, &style).as_str());
+                emit(&piece[1..]);
+            }
+            None => emit(piece),
+        }
+        after_continuation &= piece.is_empty();
+    };
+    if second_mode == mode {
+        emit_second(second);
+    } else {
+        convert(second, second_mode, mode, emit_second).unwrap();
+    }
+    Ok((mode, hashes.min))
+}
+
+/// The kind of literal that two literals are joined into
+fn joined_mode(first: Mode, second: Mode) -> Option<Mode> {
+    let non_raw = |mode| match mode {
+        Mode::Str | Mode::RawStr => Some(Mode::Str),
+        Mode::ByteStr | Mode::RawByteStr => Some(Mode::ByteStr),
+        Mode::CStr | Mode::RawCStr => Some(Mode::CStr),
+        Mode::Char | Mode::Byte => None,
+    };
+    if first == second {
+        non_raw(first).map(|_| first)
+    } else if non_raw(first)? == non_raw(second)? {
+        non_raw(first)
+    } else {
+        None
+    }
+}
+
+/// Whether a string continuation skips `c`
+#[inline]
+fn is_skipped(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+/// Find the range of the contents that makes up a range of the value,
+/// and the end of the unit before it (which is before the range if a string
+/// continuation is between them)
+fn locate(src: &str, mode: Mode, value: Range<usize>) -> Option<(Range<usize>, usize)> {
+    let mut offset = 0;
+    let mut prev_end = 0;
+    let mut start = None;
+    let mut end = None;
+    let mut valid = value.start <= value.end;
+    unescape_units(src, mode, |range, res| {
+        let Ok(unit) = res else {
+            return;
+        };
+        if start.is_none() && offset >= value.start {
+            valid &= offset == value.start;
+            start = Some((range.start, prev_end));
+        }
+        if end.is_none() && offset >= value.end {
+            valid &= offset == value.end;
+            end = Some(prev_end);
+        }
+        offset += match unit {
+            Unit::Char(c) => c.len_utf8(),
+            Unit::Byte(_) => 1,
+        };
+        prev_end = range.end;
+    });
+    // a range at the end of the value starts or ends after the last unit
+    valid &= start.is_some() || value.start == offset;
+    valid &= end.is_some() || value.end == offset;
+    if !valid {
+        return None;
+    }
+    let (start, before) = start.unwrap_or((src.len(), prev_end));
+    let end = if value.is_empty() {
+        start
+    } else {
+        end.unwrap_or(prev_end)
+    };
+    Some((start..end, before))
+}
+
+/// Split a replacement into the units of a literal of kind `mode`
+fn replacement_units(replacement: &str, mode: Mode, mut callback: impl FnMut(Range<usize>, Unit)) {
+    if matches!(mode, Mode::Byte | Mode::ByteStr | Mode::RawByteStr) {
+        for (i, b) in replacement.bytes().enumerate() {
+            callback(i..i + 1, Unit::from_byte(b));
+        }
+    } else {
+        for (i, c) in replacement.char_indices() {
+            callback(i..i + c.len_utf8(), Unit::Char(c));
+        }
+    }
+}
+
+/// Produce the units of the value after replacing `range` of the contents
+fn new_units(
+    src: &str,
+    mode: Mode,
+    range: &Range<usize>,
+    replacement: &str,
+    mut callback: impl FnMut(Unit),
+) {
+    let mut replaced = false;
+    unescape_units(src, mode, |unit_range, res| {
+        let Ok(unit) = res else {
+            return;
+        };
+        if !replaced && unit_range.start >= range.start {
+            replacement_units(replacement, mode, |_, unit| callback(unit));
+            replaced = true;
+        }
+        if unit_range.end <= range.start || unit_range.start >= range.end {
+            callback(unit);
+        }
+    });
+    if !replaced {
+        replacement_units(replacement, mode, |_, unit| callback(unit));
+    }
+}
@@ -0,0 +1,289 @@
+//! Escaping values into the contents of literals.
+
+use core::ffi::CStr;
+use core::fmt::{self, Write};
+use core::ops::Range;
+
+use crate::{CheckRaw, EscapeError, MixedUnit, Mode, Unescape};
+
+/// A unit of the value of any kind of literal
+///
+/// Like [`MixedUnit`], ASCII bytes are represented as chars, so that units
+/// of literals of different kinds with the same value compare equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Unit {
+    /// A char, or an ASCII byte
+    Char(char),
+    /// A high byte (`\x80`..`\xff`)
+    Byte(u8),
+}
+
+impl Unit {
+    #[inline]
+    pub(crate) fn from_byte(b: u8) -> Self {
+        if b.is_ascii() {
+            Unit::Char(b as char)
+        } else {
+            Unit::Byte(b)
+        }
+    }
+}
+
+impl From<MixedUnit> for Unit {
+    #[inline]
+    fn from(unit: MixedUnit) -> Self {
+        match unit {
+            MixedUnit::Char(c) => Unit::Char(c.get()),
+            MixedUnit::HighByte(b) => Unit::Byte(b.get()),
+        }
+    }
+}
+
+/// Unescape a literal of any kind into units
+///
+/// Takes the contents of a literal (without quotes)
+/// and produces a sequence of units or errors,
+/// which are returned by invoking `callback`.
+pub(crate) fn unescape_units(
+    src: &str,
+    mode: Mode,
+    mut callback: impl FnMut(Range<usize>, Result<Unit, EscapeError>),
+) {
+    match mode {
+        Mode::Char => {
+            let mut chars = src.chars();
+            match str::unescape_single(&mut chars) {
+                Ok(c) => callback(0..src.len(), Ok(Unit::Char(c))),
+                Err(e) => callback(0..(src.len() - chars.as_str().len()), Err(e)),
+            }
+        }
+        Mode::Byte => {
+            let mut chars = src.chars();
+            match <[u8]>::unescape_single(&mut chars) {
+                Ok(b) => callback(0..src.len(), Ok(Unit::from_byte(b))),
+                Err(e) => callback(0..(src.len() - chars.as_str().len()), Err(e)),
+            }
+        }
+        Mode::Str => str::unescape(src, |range, res| callback(range, res.map(Unit::Char))),
+        Mode::ByteStr => {
+            <[u8]>::unescape(src, |range, res| callback(range, res.map(Unit::from_byte)))
+        }
+        Mode::CStr => CStr::unescape(src, |range, res| callback(range, res.map(Unit::from))),
+        Mode::RawStr => str::check_raw(src, |range, res| callback(range, res.map(Unit::Char))),
+        Mode::RawByteStr => {
+            <[u8]>::check_raw(src, |range, res| callback(range, res.map(Unit::from_byte)))
+        }
+        Mode::RawCStr => CStr::check_raw(src, |range, res| {
+            callback(range, res.map(|c| Unit::Char(c.get())))
+        }),
+    }
+}
+
+/// The escaped form of a single unit, at most as long as `\u{1_0_f_f_f_f}`
+#[derive(Clone, Default)]
+pub(crate) struct Escaped {
+    buf: [u8; 16],
+    len: usize,
+}
+
+impl Escaped {
+    #[inline]
+    pub(crate) fn as_str(&self) -> &str {
+        // only ever extended with whole `str`s
+        core::str::from_utf8(&self.buf[..self.len]).unwrap()
+    }
+}
+
+impl Write for Escaped {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let buf = self
+            .buf
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(fmt::Error)?;
+        buf.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+/// How to write escapes that can be written in more than one way
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EscapeStyle {
+    /// Write hex digits in uppercase (`\xFF`, `\u{E9}`) instead of lowercase (`\xff`, `\u{e9}`).
+    pub uppercase_hex: bool,
+    /// Write ASCII control chars as unicode escapes (`\u{1b}`) instead of
+    /// hex escapes (`\x1b`) where allowed, i.e. not in byte and byte string literals.
+    pub unicode_for_ascii: bool,
+    /// Pad unicode escapes with leading zeros to at least this many digits
+    /// (e.g. `\u{00e9}` for 4). At most 6.
+    pub unicode_min_digits: u8,
+    /// Separate the digits of unicode escapes with underscores into groups of
+    /// this many digits, counted from the right (e.g. `\u{1_f980}` for 4).
+    /// No underscores for 0.
+    pub unicode_underscore_groups: u8,
+}
+
+impl EscapeStyle {
+    /// Write a hex escape for `b`
+    pub(crate) fn write_hex(&self, out: &mut impl Write, b: u8) -> fmt::Result {
+        if self.uppercase_hex {
+            write!(out, "\\x{b:02X}")
+        } else {
+            write!(out, "\\x{b:02x}")
+        }
+    }
+
+    /// Write a unicode escape for `c`
+    fn write_unicode(&self, out: &mut impl Write, c: char) -> fmt::Result {
+        let c = u32::from(c);
+        let width = usize::from(self.unicode_min_digits.min(6));
+        let mut digits = Escaped::default();
+        if self.uppercase_hex {
+            write!(digits, "{c:0width$X}")?;
+        } else {
+            write!(digits, "{c:0width$x}")?;
+        }
+        let digits = digits.as_str();
+        let group = usize::from(self.unicode_underscore_groups);
+        out.write_str("\\u{")?;
+        for (i, d) in digits.char_indices() {
+            if group > 0 && i > 0 && (digits.len() - i) % group == 0 {
+                out.write_char('_')?;
+            }
+            out.write_char(d)?;
+        }
+        out.write_char('}')
+    }
+}
+
+/// Escape a single unit for a literal of kind `mode`
+///
+/// Returns the text that represents `unit` in the contents of the literal,
+/// or the error that writing the unit there would produce.
+pub(crate) fn escape_unit(
+    unit: Unit,
+    mode: Mode,
+    style: &EscapeStyle,
+) -> Result<Escaped, EscapeError> {
+    let err = match unit {
+        Unit::Byte(_) => match mode {
+            Mode::Byte | Mode::ByteStr | Mode::CStr => None,
+            Mode::RawByteStr => Some(EscapeError::NonAsciiCharInByte),
+            Mode::RawCStr => Some(EscapeError::NonUtf8HighByte),
+            Mode::Char | Mode::Str | Mode::RawStr => Some(EscapeError::OutOfRangeHexEscape),
+        },
+        Unit::Char(c) => match mode {
+            Mode::Byte | Mode::ByteStr | Mode::RawByteStr if !c.is_ascii() => {
+                Some(EscapeError::NonAsciiCharInByte)
+            }
+            Mode::CStr | Mode::RawCStr if c == '\0' => Some(EscapeError::NulInCStr),
+            _ if mode.is_raw() && c == '\r' => Some(EscapeError::BareCarriageReturnInRawString),
+            _ => None,
+        },
+    };
+    if let Some(err) = err {
+        return Err(err);
+    }
+    let mut out = Escaped::default();
+    match unit {
+        Unit::Byte(b) => style.write_hex(&mut out, b),
+        Unit::Char(c) if mode.is_raw() => out.write_char(c),
+        Unit::Char('\\') => out.write_str("\\\\"),
+        Unit::Char('\n') => out.write_str("\\n"),
+        Unit::Char('\r') => out.write_str("\\r"),
+        Unit::Char('\t') => out.write_str("\\t"),
+        Unit::Char('\0') => out.write_str("\\0"),
+        Unit::Char('"') if mode.in_double_quotes() => out.write_str("\\\""),
+        Unit::Char('\'') if !mode.in_double_quotes() => out.write_str("\\'"),
+        Unit::Char(c) if c.is_ascii_control() => {
+            if style.unicode_for_ascii && !matches!(mode, Mode::Byte | Mode::ByteStr) {
+                style.write_unicode(&mut out, c)
+            } else {
+                style.write_hex(&mut out, c as u8)
+            }
+        }
+        Unit::Char(c) => out.write_char(c),
+    }
+    .unwrap();
+    debug_assert!(unescapes_to(out.as_str(), mode, unit));
+    Ok(out)
+}
+
+/// Escape a non-ASCII char as a unicode escape
+///
+/// NOTE: Only valid in char, string and C string literals.
+pub(crate) fn escape_unicode(c: char, style: &EscapeStyle) -> Escaped {
+    let mut out = Escaped::default();
+    style.write_unicode(&mut out, c).unwrap();
+    debug_assert!(unescapes_to(out.as_str(), Mode::Str, Unit::Char(c)));
+    out
+}
+
+/// Check that `src` is the contents of a literal of kind `mode` whose value is `unit`
+fn unescapes_to(src: &str, mode: Mode, unit: Unit) -> bool {
+    let mut units = 0;
+    let mut same = true;
+    unescape_units(src, mode, |_, res| {
+        units += 1;
+        same &= res == Ok(unit);
+    });
+    units == 1 && same
+}
+
+/// Escape a char for a char literal
+///
+/// Produces the contents of a char literal (without quotes) with value `c`,
+/// which are returned by invoking `emit`.
+pub fn escape_char(c: char, style: &EscapeStyle, mut emit: impl FnMut(&str)) {
+    let escaped = escape_unit(Unit::Char(c), Mode::Char, style).unwrap();
+    emit(escaped.as_str())
+}
+
+/// Escape a byte for a byte literal
+///
+/// Produces the contents of a byte literal (without quotes) with value `b`,
+/// which are returned by invoking `emit`.
+pub fn escape_byte(b: u8, style: &EscapeStyle, mut emit: impl FnMut(&str)) {
+    let escaped = escape_unit(Unit::from_byte(b), Mode::Byte, style).unwrap();
+    emit(escaped.as_str())
+}
+
+/// Escape a string for a string literal
+///
+/// Produces the contents of a string literal (without quotes) with value `value`
+/// as a sequence of pieces, which are returned by invoking `emit`.
+pub fn escape_str(value: &str, style: &EscapeStyle, mut emit: impl FnMut(&str)) {
+    for c in value.chars() {
+        let escaped = escape_unit(Unit::Char(c), Mode::Str, style).unwrap();
+        emit(escaped.as_str());
+    }
+}
+
+/// Escape a byte string for a byte string literal
+///
+/// Produces the contents of a byte string literal (without quotes) with value `value`
+/// as a sequence of pieces, which are returned by invoking `emit`.
+pub fn escape_byte_str(value: &[u8], style: &EscapeStyle, mut emit: impl FnMut(&str)) {
+    for &b in value {
+        let escaped = escape_unit(Unit::from_byte(b), Mode::ByteStr, style).unwrap();
+        emit(escaped.as_str());
+    }
+}
+
+/// Escape a C string for a C string literal
+///
+/// Produces the contents of a C string literal (without quotes) with value `value`
+/// as a sequence of pieces, which are returned by invoking `emit`.
+/// Valid UTF-8 is written as chars, other high bytes as hex escapes.
+pub fn escape_c_str(value: &CStr, style: &EscapeStyle, mut emit: impl FnMut(&str)) {
+    for chunk in value.to_bytes().utf8_chunks() {
+        for c in chunk.valid().chars() {
+            let escaped = escape_unit(Unit::Char(c), Mode::CStr, style).unwrap();
+            emit(escaped.as_str());
+        }
+        for &b in chunk.invalid() {
+            let escaped = escape_unit(Unit::Byte(b), Mode::CStr, style).unwrap();
+            emit(escaped.as_str());
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Recognizing escapes from other languages in invalid escapes.
+
+use core::ops::Range;
+
+use crate::escape::{escape_unicode, EscapeStyle, Escaped};
+use crate::{EscapeError, Mode, Suggestion};
+
+/// An escape from another language, recognized by [`foreign_escape`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignEscape {
+    /// Range of the escape in the contents
+    pub range: Range<usize>,
+    /// Kind of the escape
+    pub kind: ForeignEscapeKind,
+    /// The equivalent Rust escape, if there is one
+    pub suggestion: Option<Suggestion>,
+}
+
+/// Kinds of escapes from other languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ForeignEscapeKind {
+    /// A unicode escape with 8 digits and no braces, like `\U0001F600` in C and Python.
+    LongUnicode,
+    /// A unicode escape with 4 digits and no braces, like `\u00e9` in C, Python and JavaScript.
+    BracelessUnicode,
+    /// A UTF-16 surrogate pair of unicode escapes, like `\ud83d\ude00` in JavaScript.
+    SurrogatePair,
+    /// A unicode escape by name, like `\N{SNOWMAN}` in Python.
+    NamedUnicode,
+    /// An escape of a control char, like `\a` or `\e` in C, whose value is given.
+    ControlChar(u8),
+    /// An octal escape, like `\12` in C and Python, or `\012` in C string literals.
+    Octal,
+}
+
+impl ForeignEscapeKind {
+    /// A short explanation of how Rust differs
+    pub fn explanation(self) -> &'static str {
+        match self {
+            ForeignEscapeKind::LongUnicode => {
+                "Rust has no `\\U` escapes, unicode escapes are written `\\u{...}` with up to 6 hex digits"
+            }
+            ForeignEscapeKind::BracelessUnicode => {
+                "unicode escapes are written with braces in Rust, like `\\u{...}`"
+            }
+            ForeignEscapeKind::SurrogatePair => {
+                "Rust has no UTF-16 surrogate escapes, chars are written as a single `\\u{...}` escape"
+            }
+            ForeignEscapeKind::NamedUnicode => {
+                "Rust has no named unicode escapes, chars are written by code point like `\\u{...}`"
+            }
+            ForeignEscapeKind::ControlChar(_) => {
+                "Rust only has the `\\n`, `\\r`, `\\t` and `\\0` escapes for control chars, others are written `\\x..`"
+            }
+            ForeignEscapeKind::Octal => {
+                "Rust has no octal escapes, use hex escapes like `\\x..` or unicode escapes instead"
+            }
+        }
+    }
+}
+
+/// Recognize an escape from another language at an error
+///
+/// Takes the contents of a literal (without quotes) and an error in them, as
+/// produced by [`check_for_errors`](crate::check_for_errors), and returns the
+/// escape from another language that caused the error, if any. The range of
+/// the escape can extend beyond that of the error, so for a UTF-16 surrogate
+/// pair it is only returned for the error at the high half.
+pub fn foreign_escape(
+    src: &str,
+    mode: Mode,
+    range: Range<usize>,
+    error: &EscapeError,
+) -> Option<ForeignEscape> {
+    if mode.is_raw() {
+        return None;
+    }
+    let rest = src.get(range.start..)?.strip_prefix('\\')?;
+    let (kind, len, value) = match (error, rest.chars().next()?) {
+        (EscapeError::InvalidEscape, 'U') => {
+            let digits = rest.get(1..9)?;
+            (ForeignEscapeKind::LongUnicode, 9, hex(digits)?)
+        }
+        (EscapeError::NoBraceInUnicodeEscape, 'u') => {
+            let digits = rest.get(1..5)?;
+            let value = hex(digits)?;
+            // the low half of a pair is part of the escape of the high half
+            if (0xdc00..0xe000).contains(&value) && follows_high_surrogate(&src[..range.start]) {
+                return None;
+            }
+            let low = rest
+                .get(5..11)
+                .and_then(|low| low.strip_prefix("\\u"))
+                .and_then(hex)
+                .filter(|low| (0xdc00..0xe000).contains(low));
+            match low {
+                Some(low) if (0xd800..0xdc00).contains(&value) => {
+                    let value = 0x10000 + ((value - 0xd800) << 10) + (low - 0xdc00);
+                    (ForeignEscapeKind::SurrogatePair, 11, value)
+                }
+                _ => (ForeignEscapeKind::BracelessUnicode, 5, value),
+            }
+        }
+        (EscapeError::InvalidEscape, 'N') => {
+            let name = rest.strip_prefix("N{")?;
+            let name = &name[..name.find('}')?];
+            let valid = |c: char| c.is_ascii_alphanumeric() || c == ' ' || c == '-';
+            if name.is_empty() || !name.chars().all(valid) {
+                return None;
+            }
+            let len = name.len() + 3;
+            #[cfg(feature = "unicode-names")]
+            let value = unicode_names2::character(name).map_or(u32::MAX, u32::from);
+            // without the names, the escape is recognized but not translated
+            #[cfg(not(feature = "unicode-names"))]
+            let value = u32::MAX;
+            (ForeignEscapeKind::NamedUnicode, len, value)
+        }
+        (EscapeError::InvalidEscape, c @ ('a' | 'b' | 'e' | 'f' | 'v')) => {
+            let b = match c {
+                'a' => 0x07,
+                'b' => 0x08,
+                'e' => 0x1b,
+                'f' => 0x0c,
+                _ => 0x0b,
+            };
+            (ForeignEscapeKind::ControlChar(b), 1, u32::from(b))
+        }
+        (EscapeError::InvalidEscape, '1'..='7') => {
+            let (len, value) = octal_digits(rest, 3);
+            (ForeignEscapeKind::Octal, len, value)
+        }
+        // `\0` is an error in C string literals, so `\012` is unlikely to mean a nul
+        (EscapeError::NulInCStr, '0')
+            if rest[1..].starts_with(|c: char| ('0'..='7').contains(&c)) =>
+        {
+            let (len, value) = octal_digits(rest, 3);
+            (ForeignEscapeKind::Octal, len, value)
+        }
+        _ => return None,
+    };
+    let uppercase = rest[1..len].bytes().any(|b| matches!(b, b'A'..=b'F'));
+    let suggestion = suggest(kind, value, mode, uppercase);
+    Some(ForeignEscape {
+        range: range.start..range.start + 1 + len,
+        kind,
+        suggestion,
+    })
+}
+
+/// The length and value of the octal digits (at most `max` of them) that `src`
+/// starts with, like in C
+pub(crate) fn octal_digits(src: &str, max: usize) -> (usize, u32) {
+    src.bytes()
+        .take(max)
+        .take_while(|b| (b'0'..=b'7').contains(b))
+        .fold((0, 0), |(len, value), b| {
+            (len + 1, value * 8 + u32::from(b - b'0'))
+        })
+}
+
+/// Whether `src` ends with a JavaScript escape of a high surrogate (`\ud83d`)
+fn follows_high_surrogate(src: &str) -> bool {
+    let Some(start) = src.len().checked_sub(6) else {
+        return false;
+    };
+    let high = src
+        .get(start..)
+        .and_then(|high| high.strip_prefix("\\u"))
+        .and_then(hex);
+    // the backslash is not itself escaped
+    let backslashes = src[..start]
+        .bytes()
+        .rev()
+        .take_while(|&b| b == b'\\')
+        .count();
+    high.is_some_and(|high| (0xd800..0xdc00).contains(&high)) && backslashes % 2 == 0
+}
+
+/// The value of exactly 4 or 8 hex digits
+fn hex(digits: &str) -> Option<u32> {
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
+
+/// Suggest a Rust escape for the value of a foreign escape
+///
+/// Control chars and octal escapes are bytes in C, but chars in Python strings.
+fn suggest(kind: ForeignEscapeKind, value: u32, mode: Mode, uppercase: bool) -> Option<Suggestion> {
+    let unicode = matches!(
+        kind,
+        ForeignEscapeKind::LongUnicode
+            | ForeignEscapeKind::BracelessUnicode
+            | ForeignEscapeKind::SurrogatePair
+            | ForeignEscapeKind::NamedUnicode
+    );
+    let style = EscapeStyle {
+        uppercase_hex: uppercase && kind != ForeignEscapeKind::NamedUnicode,
+        ..EscapeStyle::default()
+    };
+    let bytes = matches!(mode, Mode::Byte | Mode::ByteStr);
+    let hex = match mode {
+        Mode::Byte | Mode::ByteStr => value < 0x80 || !unicode && value < 0x100,
+        Mode::CStr => !unicode && value < 0x100,
+        _ => !unicode && value < 0x80,
+    };
+    if value == 0 && mode == Mode::CStr {
+        None
+    } else if hex {
+        let mut out = Escaped::default();
+        style.write_hex(&mut out, value as u8).unwrap();
+        Some(Suggestion(out))
+    } else if bytes {
+        None
+    } else {
+        char::from_u32(value).map(|c| Suggestion(escape_unicode(c, &style)))
+    }
+}
@@ -0,0 +1,231 @@
+//! Rewriting the contents of literals without changing their value.
+
+use core::ops::Range;
+
+use crate::escape::{escape_unicode, escape_unit, unescape_units, EscapeStyle, Escaped, Unit};
+use crate::{EscapeError, Mode};
+
+/// Rewrite the contents of a literal in canonical form
+///
+/// Takes the contents of a literal (without quotes) and produces contents
+/// with the same value in which every escape is written in canonical form,
+/// as a sequence of pieces which are returned by invoking `emit`:
+///
+/// - Escapes of printable ASCII chars that need no escape are replaced by
+///   the char, e.g. `\u{41}` or `\x41` by `A`, `\'` in strings by `'`
+///   and `\"` in chars by `"`.
+/// - Other escapes of ASCII chars become simple escapes (e.g. `\n`, `\0`)
+///   or hex escapes (or unicode escapes, see [`EscapeStyle::unicode_for_ascii`]).
+/// - Unicode escapes of non-ASCII chars are kept, but written with the
+///   digits and underscores given by `style`.
+/// - Hex escapes of high bytes are kept, with the digits given by `style`.
+///
+/// Chars that are written directly and string continuations are kept.
+/// Fails without producing any output if `src` has errors, returning the
+/// first error with its range.
+pub fn canonicalize(
+    src: &str,
+    mode: Mode,
+    style: &EscapeStyle,
+    mut emit: impl FnMut(&str),
+) -> Result<(), (Range<usize>, EscapeError)> {
+    first_error(src, mode)?;
+
+    let mut pos = 0;
+    unescape_units(src, mode, |range, res| {
+        let Ok(unit) = res else {
+            // only warnings, which are about continuations kept as they are
+            return;
+        };
+        // text between units is a string continuation
+        let after_continuation = range.start > pos;
+        if after_continuation {
+            emit(&src[pos..range.start]);
+        }
+        pos = range.end;
+        let piece = &src[range];
+        if mode.is_raw() || !piece.starts_with('\\') {
+            emit(piece);
+            return;
+        }
+        let canonical = match unit {
+            Unit::Char(c) if !c.is_ascii() => escape_unicode(c, style),
+            unit => escape_unit(unit, mode, style).unwrap(),
+        };
+        // a space directly after a continuation would be skipped
+        if after_continuation && canonical.as_str().starts_with(' ') {
+            emit(piece);
+        } else {
+            emit(canonical.as_str());
+        }
+    });
+    if pos < src.len() {
+        emit(&src[pos..]);
+    }
+    Ok(())
+}
+
+/// Return the first error (but not warning) in a literal, if any
+pub(crate) fn first_error(src: &str, mode: Mode) -> Result<(), (Range<usize>, EscapeError)> {
+    let mut result = Ok(());
+    unescape_units(src, mode, |range, res| match res {
+        Err(err) if err.is_fatal() && result.is_ok() => result = Err((range, err)),
+        _ => {}
+    });
+    result
+}
+
+/// Options for [`wrap`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrapOptions {
+    /// Maximal number of chars per line, including the `\` at the end of
+    /// continued lines.
+    pub width: usize,
+    /// Column (in chars) at which the contents start on the first line,
+    /// i.e. after the opening quote.
+    pub first_column: usize,
+    /// Number of spaces at the start of continuation lines.
+    pub indent: usize,
+    /// How to escape whitespace at the start of continuation lines.
+    pub style: EscapeStyle,
+}
+
+/// Wrap the contents of a literal at a column limit
+///
+/// Takes the contents of a literal (without quotes) and produces contents
+/// with the same value in which lines longer than `options.width` are broken
+/// with string continuations (`\` followed by a newline and indentation),
+/// as a sequence of pieces which are returned by invoking `emit`.
+///
+/// Lines are broken between escapes and chars, preferably after a space.
+/// Whitespace at the start of a continuation line, which the continuation
+/// would skip, is escaped. Existing continuations are kept as they are.
+/// Pieces that are longer than the width on their own are not broken.
+///
+/// Only string, byte string and C string literals have string continuations;
+/// the contents of other literals are produced unchanged.
+/// Fails without producing any output if `src` has errors, returning the
+/// first error with its range.
+pub fn wrap(
+    src: &str,
+    mode: Mode,
+    options: &WrapOptions,
+    mut emit: impl FnMut(&str),
+) -> Result<(), (Range<usize>, EscapeError)> {
+    first_error(src, mode)?;
+    if !matches!(mode, Mode::Str | Mode::ByteStr | Mode::CStr) {
+        emit(src);
+        return Ok(());
+    }
+
+    let mut wrapper = Wrapper {
+        src,
+        mode,
+        options,
+        emit: &mut emit,
+        line_start: 0,
+        col: options.first_column,
+        prev: None,
+        word_break: None,
+    };
+    unescape_units(src, mode, |range, res| {
+        if let Ok(unit) = res {
+            wrapper.push(range, unit);
+        }
+    });
+    if wrapper.line_start < src.len() {
+        (wrapper.emit)(&src[wrapper.line_start..]);
+    }
+    Ok(())
+}
+
+/// State of [`wrap`] between units
+struct Wrapper<'a, F> {
+    src: &'a str,
+    mode: Mode,
+    options: &'a WrapOptions,
+    emit: &'a mut F,
+    /// Start of the text in `src` that is not emitted yet
+    line_start: usize,
+    /// Column after the last unit
+    col: usize,
+    /// Range of the last unit, and whether it was a raw space
+    prev: Option<(Range<usize>, bool)>,
+    /// Start of the last unit in the current line that follows a space,
+    /// and the column before it
+    word_break: Option<(usize, usize)>,
+}
+
+impl<F: FnMut(&str)> Wrapper<'_, F> {
+    fn push(&mut self, range: Range<usize>, unit: Unit) {
+        let piece = &self.src[range.clone()];
+        let is_raw_space = piece == " ";
+        if let Some((prev, prev_is_raw_space)) = self.prev.replace((range.clone(), is_raw_space)) {
+            if prev.end < range.start {
+                // An existing continuation ends the line.
+                let skipped = &self.src[prev.end..range.start];
+                (self.emit)(&self.src[self.line_start..range.start]);
+                self.line_start = range.start;
+                self.col = skipped.len() - skipped.rfind('\n').map_or(0, |pos| pos + 1);
+                self.word_break = None;
+            } else if prev_is_raw_space && !piece.starts_with(char::is_whitespace) {
+                self.word_break = Some((range.start, self.col));
+            }
+        }
+
+        let width = piece.chars().count();
+        if self.overflows(range.start, width) {
+            if let Some((pos, col)) = self.word_break.take() {
+                self.break_line(pos);
+                self.col = self.options.indent + self.col - col;
+            }
+        }
+        if self.overflows(range.start, width) {
+            self.break_line(range.start);
+            self.col = self.options.indent;
+            if piece.starts_with(char::is_whitespace) {
+                // The continuation would skip the whitespace, so escape it.
+                let escaped = escape_whitespace(unit, self.mode, &self.options.style);
+                (self.emit)(escaped.as_str());
+                self.line_start = range.end;
+                self.col += escaped.as_str().len();
+                return;
+            }
+        }
+        self.col += width;
+    }
+
+    /// Whether a piece of `width` chars at `pos` does not fit on the line,
+    /// leaving room for a `\`, and the line can be broken before it
+    fn overflows(&self, pos: usize, width: usize) -> bool {
+        self.line_start < pos && self.col + width + 1 > self.options.width
+    }
+
+    /// Emit the line up to `pos` and a continuation
+    fn break_line(&mut self, pos: usize) {
+        (self.emit)(&self.src[self.line_start..pos]);
+        (self.emit)("\\\n");
+        let mut indent = self.options.indent;
+        while indent > 0 {
+            const SPACES: &str = "                                ";
+            let n = indent.min(SPACES.len());
+            (self.emit)(&SPACES[..n]);
+            indent -= n;
+        }
+        self.line_start = pos;
+        self.word_break = None;
+    }
+}
+
+/// Escape a whitespace char that a string continuation before it would skip
+pub(crate) fn escape_whitespace(unit: Unit, mode: Mode, style: &EscapeStyle) -> Escaped {
+    match unit {
+        Unit::Char(' ') => {
+            let mut escaped = Escaped::default();
+            style.write_hex(&mut escaped, b' ').unwrap();
+            escaped
+        }
+        Unit::Char(c) if !c.is_ascii() => escape_unicode(c, style),
+        unit => escape_unit(unit, mode, style).unwrap(),
+    }
+}
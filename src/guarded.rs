@@ -0,0 +1,91 @@
+//! Guarded string literals (`#"..."#`), reserved in Rust 2024 by
+//! [RFC 3593](https://rust-lang.github.io/rfcs/3593-unprefixed-guarded-strings.html).
+//!
+//! A guarded string literal is delimited by `N` hashes followed by `"` on the
+//! left, and by `"` followed by `N` hashes on the right, with `1 <= N <= 255`.
+//!
+//! The RFC only reserves the syntax, so the meaning of the contents is an
+//! experimental interpretation of this crate: a backslash only starts an
+//! escape if it is immediately followed by `N` hashes, so with `N = 1` the
+//! contents `\n` are a backslash and an `n`, while `\#n` is a newline. After
+//! the hashes, escapes (including string continuations) are interpreted as in
+//! a string literal.
+
+use core::ops::Range;
+
+use crate::{closing_quote, skip_continuation, EscapeError, Unescape};
+
+/// Delimiters of a guarded string literal, as found by [`parse_guarded_str`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardedStr {
+    /// Number of hashes on each side of the literal
+    pub hashes: u8,
+    /// Range of the contents of the literal (without quotes and hashes)
+    pub contents: Range<usize>,
+    /// Range of the whole literal, including the closing hashes
+    pub range: Range<usize>,
+}
+
+/// Parse the delimiters of a guarded string literal
+///
+/// Takes source text starting with the opening hashes of a guarded string
+/// literal and returns the hash count and the extent of the literal,
+/// which ends at the first `"` followed by the same number of hashes.
+/// Any text after the literal is ignored.
+pub fn parse_guarded_str(src: &str) -> Result<GuardedStr, (Range<usize>, EscapeError)> {
+    let n_hashes = src.bytes().take_while(|&b| b == b'#').count();
+    if n_hashes == 0 || src.as_bytes().get(n_hashes) != Some(&b'"') {
+        return Err((0..n_hashes, EscapeError::InvalidGuardedStrDelimiter));
+    }
+    let hashes = u8::try_from(n_hashes).map_err(|_| (0..n_hashes, EscapeError::TooManyHashes))?;
+
+    let contents_start = n_hashes + 1;
+    let rest = &src[contents_start..];
+    let len =
+        closing_quote(rest, n_hashes).ok_or((0..src.len(), EscapeError::UnterminatedGuardedStr))?;
+    let contents = contents_start..contents_start + len;
+    Ok(GuardedStr {
+        hashes,
+        range: 0..contents.end + 1 + n_hashes,
+        contents,
+    })
+}
+
+/// Unescape a guarded string literal
+///
+/// Takes the contents of a guarded string literal (without quotes and hashes)
+/// and the number of hashes delimiting it,
+/// and produces a sequence of escaped characters or errors,
+/// which are returned by invoking `callback`.
+pub fn unescape_guarded_str(
+    src: &str,
+    hashes: u8,
+    mut callback: impl FnMut(Range<usize>, Result<char, EscapeError>),
+) {
+    let hashes = usize::from(hashes);
+    let mut chars = src.chars();
+    while let Some(c) = chars.next() {
+        let start = src.len() - chars.as_str().len() - c.len_utf8();
+        let res = match c {
+            '\\' if chars
+                .as_str()
+                .bytes()
+                .take(hashes)
+                .filter(|&b| b == b'#')
+                .count()
+                == hashes =>
+            {
+                chars = chars.as_str()[hashes..].chars();
+                let callback_err = |range, err| callback(range, Err(err));
+                if skip_continuation(&mut chars, start, 1 + hashes, callback_err) {
+                    continue;
+                }
+                str::unescape_1(&mut chars)
+            }
+            '\r' => Err(EscapeError::BareCarriageReturn),
+            c => Ok(c),
+        };
+        let end = src.len() - chars.as_str().len();
+        callback(start..end, res);
+    }
+}
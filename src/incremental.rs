@@ -0,0 +1,215 @@
+//! Revalidating literals incrementally after edits.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::escape::unescape_units;
+use crate::{check_for_errors, EscapeError, Mode};
+
+/// Minimal distance between the positions at which rescanning can start
+const SYNC_INTERVAL: usize = 64;
+
+/// Errors in the contents of a literal, kept up to date across edits
+///
+/// Holds the errors and warnings that [`check_for_errors`] produces for the
+/// contents of a literal, together with positions at which unescaping can be
+/// resumed. After an edit, [`IncrementalChecker::edit`] only rescans from the
+/// last such position before the edit up to the first position after it at
+/// which unescaping proceeds like before.
+#[derive(Debug)]
+pub struct IncrementalChecker {
+    mode: Mode,
+    len: usize,
+    errors: Vec<(Range<usize>, EscapeError)>,
+    /// Starts of units (or string continuations), at least `SYNC_INTERVAL` apart
+    sync: Vec<usize>,
+}
+
+impl IncrementalChecker {
+    /// Check the contents of a literal (without quotes)
+    pub fn new(src: &str, mode: Mode) -> Self {
+        let mut checker = IncrementalChecker {
+            mode,
+            len: src.len(),
+            errors: Vec::new(),
+            sync: Vec::new(),
+        };
+        checker.rescan_all(src);
+        checker
+    }
+
+    /// The errors and warnings in the contents, in the order in which
+    /// [`check_for_errors`] produces them
+    pub fn errors(&self) -> &[(Range<usize>, EscapeError)] {
+        &self.errors
+    }
+
+    /// Update the errors after an edit
+    ///
+    /// Takes the new contents `src`, which are the previous contents with the
+    /// range `old` replaced by `new_len` bytes, and returns the range of `src`
+    /// that was rescanned. Errors after that range are kept, with their
+    /// ranges shifted.
+    pub fn edit(&mut self, src: &str, old: Range<usize>, new_len: usize) -> Range<usize> {
+        debug_assert_eq!(src.len(), self.len - old.len() + new_len);
+        self.len = src.len();
+        if !self.mode.in_double_quotes() {
+            // char and byte literals can only be checked as a whole
+            self.rescan_all(src);
+            return 0..src.len();
+        }
+
+        // Text before the edit is unchanged, but the unit (or continuation)
+        // before it may depend on what follows, so resume at a start before.
+        let n_before = self.sync.partition_point(|&pos| pos < old.start).max(1);
+        let from = self.sync[n_before - 1];
+        let new_end = old.start + new_len;
+        let to_old = |pos: usize| pos - new_end + old.end;
+        let sync = &self.sync;
+        let mut errors = Vec::new();
+        let mut new_sync = Vec::new();
+        let converged = scan(
+            src,
+            self.mode,
+            from,
+            |pos| pos >= new_end && sync.binary_search(&to_old(pos)).is_ok(),
+            &mut errors,
+            &mut new_sync,
+        );
+        let to = converged.unwrap_or(src.len());
+
+        let errors_before = self.errors.partition_point(|(range, _)| range.start < from);
+        let (errors_after, sync_after) = match converged {
+            Some(pos) => (
+                self.errors
+                    .partition_point(|(range, _)| range.start < to_old(pos)),
+                // the start of the contents stays where it is
+                self.sync
+                    .partition_point(|&sync| sync < to_old(pos))
+                    .max(n_before),
+            ),
+            None => (self.errors.len(), self.sync.len()),
+        };
+        let shift = |pos: usize| pos - old.end + new_end;
+        for (range, _) in &mut self.errors[errors_after..] {
+            *range = shift(range.start)..shift(range.end);
+        }
+        for pos in &mut self.sync[sync_after..] {
+            *pos = shift(*pos);
+        }
+        self.errors.splice(errors_before..errors_after, errors);
+        self.sync.splice(n_before..sync_after, new_sync);
+        from..to
+    }
+
+    fn rescan_all(&mut self, src: &str) {
+        self.errors.clear();
+        self.sync.clear();
+        self.sync.push(0);
+        if self.mode.in_double_quotes() {
+            scan(
+                src,
+                self.mode,
+                0,
+                |_| false,
+                &mut self.errors,
+                &mut self.sync,
+            );
+        } else {
+            check_for_errors(src, self.mode, |range, err| self.errors.push((range, err)));
+        }
+    }
+}
+
+/// Unescape `src` starting at the start of a unit `from`, until the start of
+/// a unit (or string continuation) at which `converged` holds
+///
+/// Pushes the errors and the starts of units at least `SYNC_INTERVAL` after
+/// `from` and each other, and returns the position at which it converged.
+/// Only reads the contents up to a bit after that position.
+fn scan(
+    src: &str,
+    mode: Mode,
+    from: usize,
+    converged: impl Fn(usize) -> bool,
+    errors: &mut Vec<(Range<usize>, EscapeError)>,
+    sync: &mut Vec<usize>,
+) -> Option<usize> {
+    let mut pos = from;
+    let mut last_sync = from;
+    let mut window = 4 * SYNC_INTERVAL;
+    loop {
+        let mut end = src.len().min(pos + window);
+        while !src.is_char_boundary(end) {
+            end += 1;
+        }
+        // Unescaping a prefix of `src` can go wrong at its end.
+        let truncated = end < src.len();
+        let start = pos;
+        let mut stop = None;
+        // errors and warnings about a continuation, until what follows it
+        let mut pending = Vec::new();
+        // end of the spaces and tabs after an invalid `\`, inside which
+        // unescaping cannot resume, as a newline after them would make the
+        // `\` start a continuation
+        let mut no_sync = 0;
+        unescape_units(&src[start..end], mode, |range, res| {
+            let range = range.start + start..range.end + start;
+            if stop.is_some() {
+                return;
+            }
+            // unescaping cannot resume in the middle of a continuation, and
+            // its warnings come after the errors in its whitespace
+            let resumable = match &res {
+                Ok(_) => true,
+                Err(EscapeError::WhitespaceAfterBackslash) => false,
+                Err(err) => err.is_fatal(),
+            };
+            if resumable && range.start >= no_sync && converged(range.start) {
+                stop = Some(Some(range.start));
+                return;
+            }
+            if truncated && range.end >= end {
+                stop = Some(None);
+                return;
+            }
+            if res == Err(EscapeError::InvalidEscape) {
+                let spaces = src[range.start + 1..].trim_start_matches([' ', '\t']);
+                no_sync = src.len() - spaces.len();
+                if truncated && no_sync >= end {
+                    stop = Some(None);
+                    return;
+                }
+            }
+            match res {
+                Err(err) if !resumable => {
+                    pending.push((range, err));
+                    return;
+                }
+                Err(err) => {
+                    errors.append(&mut pending);
+                    errors.push((range.clone(), err));
+                }
+                Ok(_) => errors.append(&mut pending),
+            }
+            if range.start >= last_sync + SYNC_INTERVAL && range.start >= no_sync {
+                sync.push(range.start);
+                last_sync = range.start;
+            }
+            pos = range.end;
+        });
+        match stop {
+            Some(Some(converged)) => {
+                errors.append(&mut pending);
+                return Some(converged);
+            }
+            // continue after the last unit that is certainly right
+            Some(None) => window *= 2,
+            None if truncated => window *= 2,
+            None => {
+                errors.append(&mut pending);
+                return None;
+            }
+        }
+    }
+}
@@ -3,11 +3,60 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::ffi::CStr;
 use core::num::NonZero;
 use core::ops::Range;
 use core::str::Chars;
 
+#[cfg(feature = "confusables")]
+mod confusables;
+mod convert;
+mod describe;
+mod edit;
+mod escape;
+mod foreign;
+mod format;
+mod guarded;
+#[cfg(feature = "alloc")]
+mod incremental;
+mod lint;
+#[cfg(feature = "nfc")]
+mod normalize;
+mod scan;
+mod style;
+mod tokenize;
+#[cfg(feature = "unassigned")]
+mod unassigned;
+
+pub use convert::convert;
+pub use describe::{
+    describe_escape, describe_more_than_one_char, escape_chars, EscapeDescription, EscapeValue,
+    MultipleChars,
+};
+pub use edit::{edit_value, join, split_at_value, EditError, JoinError, ValueEdit};
+pub use escape::{
+    escape_byte, escape_byte_str, escape_c_str, escape_char, escape_str, EscapeStyle,
+};
+pub use foreign::{foreign_escape, ForeignEscape, ForeignEscapeKind};
+pub use format::{canonicalize, wrap, WrapOptions};
+pub use guarded::{parse_guarded_str, unescape_guarded_str, GuardedStr};
+#[cfg(feature = "alloc")]
+pub use incremental::IncrementalChecker;
+pub use lint::{
+    check_lints, Diagnostic, InvisibleChars, InvisibleClass, Level, Lint, LintConfig, Suggestion,
+};
+#[cfg(feature = "nfc")]
+pub use normalize::{first_non_nfc, normalize_nfc};
+pub use scan::{
+    check_literals, check_literals_in_edition, check_literals_in_edition_with_levels,
+    check_literals_with_levels, literals, literals_in_edition, Literal, Literals,
+};
+pub use style::detect_style;
+pub use tokenize::{tokenize, Piece};
+
 /// Errors and warnings that can occur during string, char, and byte unescaping.
 ///
 /// Mostly relating to malformed escape sequences, but also a few other problems.
@@ -61,6 +110,27 @@ pub enum EscapeError {
     /// `\0` in a C string literal.
     NulInCStr,
 
+    /// More than 255 `#`s delimiting a raw or guarded string literal.
+    TooManyHashes,
+    /// High byte that is not part of a UTF-8 encoded char, where only chars can be written.
+    NonUtf8HighByte,
+    /// Guarded string literal not starting with `#`s followed by `"`.
+    InvalidGuardedStrDelimiter,
+    /// No closing `"` followed by enough `#`s in a guarded string literal.
+    UnterminatedGuardedStr,
+
+    /// Literal kind that does not exist in the edition, e.g. `c"..."` before 2021.
+    UnavailableInEdition,
+    /// Unknown literal prefix, reserved since 2021 (e.g. `foo"..."`).
+    ReservedPrefix,
+    /// Guarded string literal or multiple `#`s, reserved since 2024 (e.g. `#"..."#`).
+    ReservedGuardedStr,
+
+    /// Spaces or tabs between a '\' and the end of the line, which make it an
+    /// invalid escape rather than a string continuation. The range covers the
+    /// whitespace, so that removing it fixes the error.
+    WhitespaceAfterBackslash,
+
     /// After a line ending with '\', the next line contains whitespace
     /// characters that are not skipped.
     UnskippedWhitespaceWarning,
@@ -71,6 +141,8 @@ pub enum EscapeError {
 
 impl EscapeError {
     /// Returns true for actual errors, as opposed to warnings.
+    ///
+    /// Warnings can be allowed or turned into errors with a [`LintConfig`].
     pub fn is_fatal(&self) -> bool {
         !matches!(
             self,
@@ -112,6 +184,103 @@ pub fn check_raw_c_str(
     CStr::check_raw(src, callback);
 }
 
+/// Compute the minimal number of hashes for a raw string literal
+///
+/// Takes the contents of a raw literal (without quotes) and returns the
+/// smallest number of `#`s on each side for which no `"` in `src` closes the
+/// literal early, or a [`EscapeError::TooManyHashes`] error for the `"`
+/// followed by hashes that would require more than 255.
+pub fn min_raw_hashes(src: &str) -> Result<u8, (Range<usize>, EscapeError)> {
+    let mut min = 0;
+    for (pos, _) in src.match_indices('"') {
+        let run = src[pos + 1..].bytes().take_while(|&b| b == b'#').count();
+        min = u8::try_from(run + 1)
+            .map_err(|_| (pos..pos + 1 + run, EscapeError::TooManyHashes))?
+            .max(min);
+    }
+    Ok(min)
+}
+
+/// Find where the contents of a raw string literal would close it early
+///
+/// Takes the contents of a raw literal (without quotes) and the number of
+/// `#`s delimiting it, and returns the range of the first `"` followed by
+/// `hashes` hashes, if any.
+pub fn find_raw_terminator(src: &str, hashes: u8) -> Option<Range<usize>> {
+    let hashes = usize::from(hashes);
+    closing_quote(src, hashes).map(|pos| pos..pos + 1 + hashes)
+}
+
+/// Find the first `"` followed by at least `n_hashes` hashes
+fn closing_quote(src: &str, n_hashes: usize) -> Option<usize> {
+    src.match_indices('"')
+        .map(|(pos, _)| pos)
+        .find(|&pos| src[pos + 1..].bytes().take_while(|&b| b == b'#').count() >= n_hashes)
+}
+
+/// Check whether a string can be written as a raw string literal
+///
+/// Returns the minimal number of `#`s delimiting the raw string literal
+/// whose value is `value` (see [`min_raw_hashes`]), or an error with the
+/// range in `value` that cannot be written in a raw string literal
+/// (a carriage return).
+pub fn raw_str_hashes(value: &str) -> Result<u8, (Range<usize>, EscapeError)> {
+    check_raw_value(value, |_| Ok(()))?;
+    min_raw_hashes(value)
+}
+
+/// Check whether a byte string can be written as a raw byte string literal
+///
+/// Returns the minimal number of `#`s delimiting the raw byte string literal
+/// whose value is `value` (see [`min_raw_hashes`]), or an error with the
+/// range in `value` that cannot be written in a raw byte string literal
+/// (a carriage return or a non-ascii byte).
+pub fn raw_byte_str_hashes(value: &[u8]) -> Result<u8, (Range<usize>, EscapeError)> {
+    if let Some(pos) = value.iter().position(|b| !b.is_ascii()) {
+        return Err((pos..pos + 1, EscapeError::NonAsciiCharInByte));
+    }
+    // ascii, so always valid utf8
+    let value = core::str::from_utf8(value).unwrap();
+    check_raw_value(value, |_| Ok(()))?;
+    min_raw_hashes(value)
+}
+
+/// Check whether a C string can be written as a raw C string literal
+///
+/// Takes the value of the C string without the nul terminator and
+/// returns the minimal number of `#`s delimiting the raw C string literal
+/// whose value is `value` (see [`min_raw_hashes`]), or an error with the
+/// range in `value` that cannot be written in a raw C string literal
+/// (a carriage return, a nul, or high bytes that are not valid UTF-8).
+pub fn raw_c_str_hashes(value: &[u8]) -> Result<u8, (Range<usize>, EscapeError)> {
+    let value = core::str::from_utf8(value).map_err(|e| {
+        let pos = e.valid_up_to();
+        (
+            pos..pos + e.error_len().unwrap_or(value.len() - pos),
+            EscapeError::NonUtf8HighByte,
+        )
+    })?;
+    check_raw_value(value, |c| CStr::char2raw_unit(c).map(drop))?;
+    min_raw_hashes(value)
+}
+
+/// Report the first char of `value` that cannot appear in a raw literal
+#[inline]
+fn check_raw_value(
+    value: &str,
+    mut check_char: impl FnMut(char) -> Result<(), EscapeError>,
+) -> Result<(), (Range<usize>, EscapeError)> {
+    for (pos, c) in value.char_indices() {
+        let res = if c == '\r' {
+            Err(EscapeError::BareCarriageReturnInRawString)
+        } else {
+            check_char(c)
+        };
+        res.map_err(|e| (pos..pos + c.len_utf8(), e))?;
+    }
+    Ok(())
+}
+
 /// Trait for checking raw string literals for validity
 trait CheckRaw {
     /// Unit type of the implementing string type (`char` for string, `u8` for byte string)
@@ -362,16 +531,11 @@ trait Unescape {
             let start = src.len() - chars.as_str().len() - c.len_utf8();
             let res = match c {
                 '\\' => {
-                    if let Some(b'\n') = chars.as_str().as_bytes().first() {
-                        let _ = chars.next();
-                        // skip whitespace for backslash newline, see [Rust language reference]
-                        // (https://doc.rust-lang.org/reference/tokens.html#string-literals).
-                        let callback_err = |range, err| callback(range, Err(err));
-                        skip_ascii_whitespace(&mut chars, start, callback_err);
+                    let callback_err = |range, err| callback(range, Err(err));
+                    if skip_continuation(&mut chars, start, 1, callback_err) {
                         continue;
-                    } else {
-                        Self::unescape_1(&mut chars)
                     }
+                    Self::unescape_1(&mut chars)
                 }
                 '"' => Err(EscapeError::EscapeOnlyChar),
                 '\r' => Err(EscapeError::BareCarriageReturn),
@@ -465,15 +629,47 @@ fn unicode_escape(chars: &mut impl Iterator<Item = char>) -> Result<u32, EscapeE
     }
 }
 
+/// Skip a string continuation, if there is one after an escape at `start`
+/// of length `escape_len` (a backslash, and the `#`s in guarded strings)
+///
+/// Spaces and tabs before the newline are reported as an error, and then
+/// skipped as well.
+fn skip_continuation(
+    chars: &mut Chars<'_>,
+    start: usize,
+    escape_len: usize,
+    mut callback: impl FnMut(Range<usize>, EscapeError),
+) -> bool {
+    let rest = chars.as_str();
+    let space = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    if rest.as_bytes().get(space) != Some(&b'\n') {
+        return false;
+    }
+    if space > 0 {
+        let space_start = start + escape_len;
+        callback(
+            space_start..space_start + space,
+            EscapeError::WhitespaceAfterBackslash,
+        );
+    }
+    *chars = rest[space + 1..].chars();
+    // skip whitespace for backslash newline, see [Rust language reference]
+    // (https://doc.rust-lang.org/reference/tokens.html#string-literals).
+    let after_newline = start + escape_len + space + 1;
+    skip_ascii_whitespace(chars, start, after_newline, callback);
+    true
+}
+
 /// Interpret a string continuation escape (https://doc.rust-lang.org/reference/expressions/literal-expr.html#string-continuation-escapes)
 ///
 /// Skip ASCII whitespace, except for the formfeed character
 /// (see [this issue](https://github.com/rust-lang/rust/issues/136600)).
 /// Warns on unescaped newline and following non-ASCII whitespace.
-#[inline] // single use in Unescape::unescape
+#[inline] // single use in skip_continuation
 fn skip_ascii_whitespace(
     chars: &mut Chars<'_>,
     start: usize,
+    after_newline: usize,
     mut callback: impl FnMut(Range<usize>, EscapeError),
 ) {
     let rest = chars.as_str();
@@ -482,8 +678,7 @@ fn skip_ascii_whitespace(
         .position(|b| b != b' ' && b != b'\t' && b != b'\n' && b != b'\r')
         .unwrap_or(rest.len());
     let (space, rest) = rest.split_at(first_non_space);
-    // backslash newline adds 2 bytes
-    let end = start + 2 + first_non_space;
+    let end = after_newline + first_non_space;
     if space.contains('\n') {
         callback(start..end, EscapeError::MultipleSkippedLinesWarning);
     }
@@ -582,7 +777,7 @@ impl Unescape for CStr {
 }
 
 /// Enum of the different kinds of literal
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
     /// `'a'`
     Char,
@@ -619,6 +814,13 @@ impl Mode {
         }
     }
 
+    pub fn is_raw(self) -> bool {
+        match self {
+            Mode::RawStr | Mode::RawByteStr | Mode::RawCStr => true,
+            Mode::Char | Mode::Byte | Mode::Str | Mode::ByteStr | Mode::CStr => false,
+        }
+    }
+
     pub fn prefix_noraw(self) -> &'static str {
         match self {
             Mode::Char | Mode::Str | Mode::RawStr => "",
@@ -626,6 +828,37 @@ impl Mode {
             Mode::CStr | Mode::RawCStr => "c",
         }
     }
+
+    /// The first edition in which literals of this kind exist
+    pub fn min_edition(self) -> Edition {
+        match self {
+            Mode::Char
+            | Mode::Byte
+            | Mode::Str
+            | Mode::RawStr
+            | Mode::ByteStr
+            | Mode::RawByteStr => Edition::Edition2015,
+            Mode::CStr | Mode::RawCStr => Edition::Edition2021,
+        }
+    }
+}
+
+/// Enum of the Rust editions, for literal rules that differ between them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Edition {
+    /// Rust 2015
+    Edition2015,
+    /// Rust 2018
+    Edition2018,
+    /// Rust 2021: adds C string literals and reserves prefixes (`foo"..."`)
+    Edition2021,
+    /// Rust 2024: reserves guarded strings (`#"..."#`)
+    Edition2024,
+}
+
+impl Edition {
+    /// The most recent edition, used by functions that take no edition
+    pub const LATEST: Edition = Edition::Edition2024;
 }
 
 /// Check a literal only for errors
@@ -638,8 +871,26 @@ impl Mode {
 pub fn check_for_errors(
     src: &str,
     mode: Mode,
+    error_callback: impl FnMut(Range<usize>, EscapeError),
+) {
+    check_for_errors_in_edition(src, mode, Edition::LATEST, error_callback)
+}
+
+/// Check a literal only for errors, following the rules of `edition`
+///
+/// Like [`check_for_errors`], but for literal kinds that do not exist in
+/// `edition` only a single [`EscapeError::UnavailableInEdition`] error
+/// covering the whole literal is produced.
+pub fn check_for_errors_in_edition(
+    src: &str,
+    mode: Mode,
+    edition: Edition,
     mut error_callback: impl FnMut(Range<usize>, EscapeError),
 ) {
+    if edition < mode.min_edition() {
+        error_callback(0..src.len(), EscapeError::UnavailableInEdition);
+        return;
+    }
     match mode {
         Mode::Char => {
             let mut chars = src.chars();
@@ -685,3 +936,46 @@ pub fn check_for_errors(
         }),
     }
 }
+
+/// Check a literal for errors and warnings, at the levels given by `config`
+///
+/// Like [`check_for_errors`], but produces each error or warning together
+/// with its level, which is [`Level::Deny`] for errors. Warnings at
+/// [`Level::Allow`] are not produced.
+pub fn check_for_errors_with_levels(
+    src: &str,
+    mode: Mode,
+    config: &LintConfig,
+    callback: impl FnMut(Range<usize>, EscapeError, Level),
+) {
+    check_for_errors_in_edition_with_levels(src, mode, Edition::LATEST, config, callback)
+}
+
+/// Check a literal for errors and warnings, following the rules of `edition`,
+/// at the levels given by `config`
+///
+/// Like [`check_for_errors_in_edition`], but produces levels like
+/// [`check_for_errors_with_levels`].
+pub fn check_for_errors_in_edition_with_levels(
+    src: &str,
+    mode: Mode,
+    edition: Edition,
+    config: &LintConfig,
+    callback: impl FnMut(Range<usize>, EscapeError, Level),
+) {
+    check_for_errors_in_edition(src, mode, edition, with_levels(config, callback))
+}
+
+/// Adapt a callback that takes levels to one for [`check_for_errors`],
+/// skipping warnings at [`Level::Allow`]
+pub(crate) fn with_levels<'a>(
+    config: &'a LintConfig,
+    mut callback: impl FnMut(Range<usize>, EscapeError, Level) + 'a,
+) -> impl FnMut(Range<usize>, EscapeError) + 'a {
+    move |range, err| {
+        let level = config.error_level(&err);
+        if level != Level::Allow {
+            callback(range, err, level);
+        }
+    }
+}
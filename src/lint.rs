@@ -0,0 +1,517 @@
+//! Opt-in lints for valid but suspicious contents of literals.
+
+use core::fmt::{self, Write};
+use core::ops::{Deref, Range};
+
+use crate::escape::{escape_unicode, escape_unit, unescape_units, EscapeStyle, Escaped, Unit};
+use crate::foreign::octal_digits;
+use crate::{EscapeError, Mode};
+
+/// A check for valid but suspicious contents of literals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// Unicode bidirectional control chars (U+202A–U+202E, U+2066–U+2069),
+    /// which can make code display differently from how it is compiled.
+    TextDirectionCodepoint,
+    /// Invisible chars written as themselves, like zero-width spaces.
+    InvisibleChar(InvisibleClass),
+    /// Non-ASCII chars written as themselves that look like an ASCII char,
+    /// which is given (e.g. `'‐'` (U+2010) for `'-'`, or Cyrillic `'а'` for `'a'`).
+    ///
+    /// Needs the `confusables` feature.
+    ConfusableChar(char),
+    /// Escapes of quotes that need no escaping: `\'` in string literals and
+    /// `\"` in char and byte literals.
+    RedundantEscape,
+    /// Unicode escapes with leading zeros, like `\u{00e9}`.
+    UnicodeEscapeLeadingZeros,
+    /// Unicode escapes with underscores, like `\u{1_f980}`.
+    UnicodeEscapeUnderscores,
+    /// Hex and unicode escapes with both uppercase and lowercase digits,
+    /// like `\u{1F98a}`.
+    MixedCaseHex,
+    /// Hex and unicode escapes of printable ASCII chars, like `\x41` for `A`.
+    EscapedPrintableAscii,
+    /// `\0` followed by a digit, like `\012`, which is a nul followed by `12`
+    /// in Rust, but an octal escape in C, whose value is given (`\x0a`).
+    ///
+    /// The suggestion keeps the nul and makes it stand out; if the value from
+    /// C is meant, write it as a hex escape instead. In C string literals,
+    /// `\0` is an error, see [`foreign_escape`](crate::foreign_escape).
+    NulFollowedByDigit(u8),
+    /// Noncharacters (U+FDD0–U+FDEF, and the last two code points of each
+    /// plane, like U+FFFE), written as themselves or escaped, which is given.
+    Noncharacter(char),
+    /// Private-use code points (U+E000–U+F8FF, and planes 15 and 16),
+    /// written as themselves or escaped, which is given.
+    PrivateUse(char),
+    /// Code points that are not assigned in Unicode 16.0.0, written as
+    /// themselves or escaped, which is given.
+    ///
+    /// Needs the `unassigned` feature.
+    Unassigned(char),
+    /// Control chars other than tab and newline (C0, DEL and C1) written as
+    /// themselves, like ESC, which is given.
+    ControlChar(char),
+}
+
+/// Kinds of invisible chars, see [`Lint::InvisibleChar`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvisibleClass {
+    /// Zero-width spaces and joiners (U+200B–U+200D, U+2060).
+    ZeroWidth,
+    /// The soft hyphen (U+00AD).
+    SoftHyphen,
+    /// The byte order mark, or zero-width no-break space (U+FEFF).
+    ByteOrderMark,
+    /// Other default-ignorable code points, like variation selectors, tags
+    /// and directional marks.
+    DefaultIgnorable,
+}
+
+impl InvisibleClass {
+    /// The class of an invisible char, or `None` if `c` is visible
+    fn of(c: char) -> Option<Self> {
+        Some(match c {
+            '\u{200b}'..='\u{200d}' | '\u{2060}' => InvisibleClass::ZeroWidth,
+            '\u{ad}' => InvisibleClass::SoftHyphen,
+            '\u{feff}' => InvisibleClass::ByteOrderMark,
+            // Default_Ignorable_Code_Point, without the bidi controls that
+            // `Lint::TextDirectionCodepoint` is about
+            '\u{34f}'
+            | '\u{61c}'
+            | '\u{115f}'..='\u{1160}'
+            | '\u{17b4}'..='\u{17b5}'
+            | '\u{180b}'..='\u{180f}'
+            | '\u{200e}'..='\u{200f}'
+            | '\u{2061}'..='\u{2065}'
+            | '\u{206a}'..='\u{206f}'
+            | '\u{3164}'
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{ffa0}'
+            | '\u{fff0}'..='\u{fff8}'
+            | '\u{1bca0}'..='\u{1bca3}'
+            | '\u{1d173}'..='\u{1d17a}'
+            | '\u{e0000}'..='\u{e0fff}' => InvisibleClass::DefaultIgnorable,
+            _ => return None,
+        })
+    }
+}
+
+/// How to report a problem
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// Do not report it.
+    #[default]
+    Allow,
+    /// Report it as a warning.
+    Warn,
+    /// Report it as an error.
+    Deny,
+}
+
+/// Levels of the lints that [`check_lints`] runs, and of the warnings that
+/// [`check_for_errors_with_levels`](crate::check_for_errors_with_levels) produces
+///
+/// By default, all lints are allowed, and the warnings are warned about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LintConfig {
+    /// Level of [`EscapeError::UnskippedWhitespaceWarning`].
+    pub unskipped_whitespace: Level,
+    /// Level of [`EscapeError::MultipleSkippedLinesWarning`].
+    pub multiple_skipped_lines: Level,
+    /// Level of [`Lint::TextDirectionCodepoint`].
+    pub text_direction_codepoint: Level,
+    /// Levels of [`Lint::InvisibleChar`] for each class of chars.
+    pub invisible_char: InvisibleChars,
+    /// Level of [`Lint::ConfusableChar`] on char and string literals.
+    pub confusable_char: Level,
+    /// Level of [`Lint::RedundantEscape`].
+    pub redundant_escape: Level,
+    /// Level of [`Lint::UnicodeEscapeLeadingZeros`].
+    pub unicode_escape_leading_zeros: Level,
+    /// Level of [`Lint::UnicodeEscapeUnderscores`].
+    pub unicode_escape_underscores: Level,
+    /// Level of [`Lint::MixedCaseHex`].
+    pub mixed_case_hex: Level,
+    /// Level of [`Lint::EscapedPrintableAscii`].
+    pub escaped_printable_ascii: Level,
+    /// Level of [`Lint::NulFollowedByDigit`].
+    pub nul_followed_by_digit: Level,
+    /// Level of [`Lint::Noncharacter`].
+    pub noncharacter: Level,
+    /// Level of [`Lint::PrivateUse`].
+    pub private_use: Level,
+    /// Level of [`Lint::Unassigned`].
+    pub unassigned: Level,
+    /// Level of [`Lint::ControlChar`].
+    pub control_char: Level,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        LintConfig {
+            unskipped_whitespace: Level::Warn,
+            multiple_skipped_lines: Level::Warn,
+            text_direction_codepoint: Level::Allow,
+            invisible_char: InvisibleChars::default(),
+            confusable_char: Level::Allow,
+            redundant_escape: Level::Allow,
+            unicode_escape_leading_zeros: Level::Allow,
+            unicode_escape_underscores: Level::Allow,
+            mixed_case_hex: Level::Allow,
+            escaped_printable_ascii: Level::Allow,
+            nul_followed_by_digit: Level::Allow,
+            noncharacter: Level::Allow,
+            private_use: Level::Allow,
+            unassigned: Level::Allow,
+            control_char: Level::Allow,
+        }
+    }
+}
+
+impl LintConfig {
+    /// The level of a lint
+    pub fn lint_level(&self, lint: Lint) -> Level {
+        match lint {
+            Lint::TextDirectionCodepoint => self.text_direction_codepoint,
+            Lint::InvisibleChar(class) => self.invisible_char.level(class),
+            Lint::ConfusableChar(_) => self.confusable_char,
+            Lint::RedundantEscape => self.redundant_escape,
+            Lint::UnicodeEscapeLeadingZeros => self.unicode_escape_leading_zeros,
+            Lint::UnicodeEscapeUnderscores => self.unicode_escape_underscores,
+            Lint::MixedCaseHex => self.mixed_case_hex,
+            Lint::EscapedPrintableAscii => self.escaped_printable_ascii,
+            Lint::NulFollowedByDigit(_) => self.nul_followed_by_digit,
+            Lint::Noncharacter(_) => self.noncharacter,
+            Lint::PrivateUse(_) => self.private_use,
+            Lint::Unassigned(_) => self.unassigned,
+            Lint::ControlChar(_) => self.control_char,
+        }
+    }
+
+    /// The level of an error or warning, which is [`Level::Deny`] for errors
+    pub fn error_level(&self, err: &EscapeError) -> Level {
+        match err {
+            EscapeError::UnskippedWhitespaceWarning => self.unskipped_whitespace,
+            EscapeError::MultipleSkippedLinesWarning => self.multiple_skipped_lines,
+            _ => Level::Deny,
+        }
+    }
+}
+
+/// Levels of [`Lint::InvisibleChar`] for each class of chars
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InvisibleChars {
+    /// Level for [`InvisibleClass::ZeroWidth`] chars.
+    pub zero_width: Level,
+    /// Level for [`InvisibleClass::SoftHyphen`].
+    pub soft_hyphen: Level,
+    /// Level for [`InvisibleClass::ByteOrderMark`].
+    pub byte_order_mark: Level,
+    /// Level for [`InvisibleClass::DefaultIgnorable`] chars.
+    pub default_ignorable: Level,
+}
+
+impl InvisibleChars {
+    /// The same level for all classes of invisible chars
+    pub const fn all(level: Level) -> Self {
+        InvisibleChars {
+            zero_width: level,
+            soft_hyphen: level,
+            byte_order_mark: level,
+            default_ignorable: level,
+        }
+    }
+
+    /// The level for a class of invisible chars
+    pub fn level(&self, class: InvisibleClass) -> Level {
+        match class {
+            InvisibleClass::ZeroWidth => self.zero_width,
+            InvisibleClass::SoftHyphen => self.soft_hyphen,
+            InvisibleClass::ByteOrderMark => self.byte_order_mark,
+            InvisibleClass::DefaultIgnorable => self.default_ignorable,
+        }
+    }
+}
+
+/// A problem found by a lint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The lint that found the problem
+    pub lint: Lint,
+    /// The level of the lint, which is not [`Level::Allow`]
+    pub level: Level,
+    /// Range of the problem in the contents
+    pub range: Range<usize>,
+    /// Whether the char that the problem is about is written as an escape
+    /// rather than as itself
+    pub escaped: bool,
+    /// Text to replace the range with to fix the problem, if any
+    pub suggestion: Option<Suggestion>,
+}
+
+/// Replacement text suggested by a [`Diagnostic`]
+#[derive(Clone)]
+pub struct Suggestion(pub(crate) Escaped);
+
+impl Deref for Suggestion {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq for Suggestion {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for Suggestion {}
+
+impl fmt::Debug for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self)
+    }
+}
+
+/// Check a literal for suspicious contents
+///
+/// Takes the contents of a literal (without quotes) and runs the lints that are
+/// not allowed in `config` on them, which report their findings by invoking
+/// `callback`.
+/// Parts of the contents with errors are skipped; those are reported by
+/// [`check_for_errors`](crate::check_for_errors).
+pub fn check_lints(
+    src: &str,
+    mode: Mode,
+    config: &LintConfig,
+    mut callback: impl FnMut(Diagnostic),
+) {
+    // end of the last unit, to recognize string continuations
+    let mut last_end = 0;
+    unescape_units(src, mode, |range, res| {
+        let unit = match res {
+            Ok(unit) => unit,
+            Err(err) => {
+                if err.is_fatal() {
+                    last_end = range.end;
+                }
+                return;
+            }
+        };
+        let after_continuation = range.start > last_end;
+        last_end = range.end;
+        let text = &src[range.clone()];
+        let escaped = !mode.is_raw() && text.starts_with('\\');
+        let mut report = |lint, suggestion| {
+            let level = config.lint_level(lint);
+            if level == Level::Allow {
+                return;
+            }
+            callback(Diagnostic {
+                lint,
+                level,
+                range: range.clone(),
+                escaped,
+                suggestion,
+            })
+        };
+        if let Unit::Char(c) = unit {
+            char_lints(c, mode, escaped, config, &mut report);
+        }
+        if escaped {
+            let after = &src[range.end..];
+            escape_lints(
+                text,
+                after,
+                unit,
+                mode,
+                after_continuation,
+                config,
+                &mut report,
+            );
+        }
+    });
+}
+
+/// Run the lints about single chars, which report their findings by invoking
+/// `report` with the lint and a suggestion
+fn char_lints(
+    c: char,
+    mode: Mode,
+    escaped: bool,
+    config: &LintConfig,
+    mut report: impl FnMut(Lint, Option<Suggestion>),
+) {
+    if config.text_direction_codepoint != Level::Allow && is_bidi_control(c) {
+        report(
+            Lint::TextDirectionCodepoint,
+            escape_suggestion(c, mode, escaped),
+        );
+    }
+    if config.noncharacter != Level::Allow && is_noncharacter(c) {
+        report(Lint::Noncharacter(c), None);
+    }
+    if config.private_use != Level::Allow && is_private_use(c) {
+        report(Lint::PrivateUse(c), None);
+    }
+    #[cfg(feature = "unassigned")]
+    if config.unassigned != Level::Allow && crate::unassigned::is_unassigned(c) {
+        report(Lint::Unassigned(c), None);
+    }
+    // escaped chars are visible
+    if escaped {
+        return;
+    }
+    match InvisibleClass::of(c) {
+        Some(class) if config.invisible_char.level(class) != Level::Allow => report(
+            Lint::InvisibleChar(class),
+            escape_suggestion(c, mode, escaped),
+        ),
+        _ => {}
+    }
+    if config.control_char != Level::Allow && c.is_control() && !matches!(c, '\t' | '\n') {
+        let suggestion = if c.is_ascii() && !mode.is_raw() {
+            escape_unit(Unit::Char(c), mode, &EscapeStyle::default())
+                .ok()
+                .map(Suggestion)
+        } else {
+            escape_suggestion(c, mode, escaped)
+        };
+        report(Lint::ControlChar(c), suggestion);
+    }
+    #[cfg(feature = "confusables")]
+    if config.confusable_char != Level::Allow
+        && matches!(mode, Mode::Char | Mode::Str | Mode::RawStr)
+    {
+        if let Some(ascii) = crate::confusables::ascii_lookalike(c) {
+            let suggestion = escape_unit(Unit::Char(ascii), mode, &EscapeStyle::default());
+            report(Lint::ConfusableChar(ascii), suggestion.ok().map(Suggestion));
+        }
+    }
+}
+
+/// Run the lints about how an escape of `unit` followed by `after` is written,
+/// which report their findings by invoking `report` with the lint and a suggestion
+fn escape_lints(
+    escape: &str,
+    after: &str,
+    unit: Unit,
+    mode: Mode,
+    after_continuation: bool,
+    config: &LintConfig,
+    mut report: impl FnMut(Lint, Option<Suggestion>),
+) {
+    let (prefix, digits, suffix) = if let Some(digits) = escape.strip_prefix("\\x") {
+        ("\\x", digits, "")
+    } else if let Some(digits) = escape.strip_prefix("\\u{") {
+        ("\\u{", digits.strip_suffix('}').unwrap_or(digits), "}")
+    } else {
+        let redundant = match escape {
+            "\\'" => mode.in_double_quotes(),
+            "\\\"" => !mode.in_double_quotes(),
+            _ => false,
+        };
+        if config.redundant_escape != Level::Allow && redundant {
+            report(Lint::RedundantEscape, Some(plain(&escape[1..])));
+        }
+        if config.nul_followed_by_digit != Level::Allow
+            && escape == "\\0"
+            && after.starts_with(|c: char| ('0'..='7').contains(&c))
+        {
+            // the 0 is the first of at most 3 octal digits
+            let (_, value) = octal_digits(after, 2);
+            report(Lint::NulFollowedByDigit(value as u8), Some(plain("\\x00")));
+        }
+        return;
+    };
+
+    if config.escaped_printable_ascii != Level::Allow {
+        match unit {
+            // whitespace right after a string continuation would be skipped
+            Unit::Char(c) if c.is_ascii_graphic() || (c == ' ' && !after_continuation) => {
+                let suggestion = escape_unit(unit, mode, &EscapeStyle::default());
+                report(Lint::EscapedPrintableAscii, suggestion.ok().map(Suggestion));
+            }
+            _ => {}
+        }
+    }
+    if prefix == "\\u{" {
+        let len = digits.bytes().filter(|&d| d != b'_').count();
+        if config.unicode_escape_leading_zeros != Level::Allow && len > 1 && digits.starts_with('0')
+        {
+            let trimmed = digits.trim_start_matches(['0', '_']);
+            let trimmed = if trimmed.is_empty() { "0" } else { trimmed };
+            report(
+                Lint::UnicodeEscapeLeadingZeros,
+                rewrite(prefix, trimmed.chars(), suffix),
+            );
+        }
+        if config.unicode_escape_underscores != Level::Allow && digits.contains('_') {
+            let digits = digits.chars().filter(|&d| d != '_');
+            report(
+                Lint::UnicodeEscapeUnderscores,
+                rewrite(prefix, digits, suffix),
+            );
+        }
+    }
+    let has = |f: fn(&u8) -> bool| digits.bytes().any(|d| f(&d));
+    if config.mixed_case_hex != Level::Allow
+        && has(u8::is_ascii_uppercase)
+        && has(u8::is_ascii_lowercase)
+    {
+        let digits = digits.chars().map(|d| d.to_ascii_lowercase());
+        report(Lint::MixedCaseHex, rewrite(prefix, digits, suffix));
+    }
+}
+
+/// A suggestion of plain text
+fn plain(text: &str) -> Suggestion {
+    let mut out = Escaped::default();
+    out.write_str(text).unwrap();
+    Suggestion(out)
+}
+
+/// Suggest an escape with other digits, if it is short enough
+fn rewrite(prefix: &str, digits: impl Iterator<Item = char>, suffix: &str) -> Option<Suggestion> {
+    let mut out = Escaped::default();
+    out.write_str(prefix).ok()?;
+    for d in digits {
+        out.write_char(d).ok()?;
+    }
+    out.write_str(suffix).ok()?;
+    Some(Suggestion(out))
+}
+
+/// Suggest escaping a char that is written as itself, where possible
+fn escape_suggestion(c: char, mode: Mode, escaped: bool) -> Option<Suggestion> {
+    let can_escape = matches!(mode, Mode::Char | Mode::Str | Mode::CStr);
+    (can_escape && !escaped).then(|| Suggestion(escape_unicode(c, &EscapeStyle::default())))
+}
+
+/// Whether `c` is a Unicode bidirectional control char that `rustc` warns about
+#[inline]
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Whether `c` is a noncharacter
+#[inline]
+fn is_noncharacter(c: char) -> bool {
+    matches!(c, '\u{fdd0}'..='\u{fdef}') || u32::from(c) & 0xfffe == 0xfffe
+}
+
+/// Whether `c` is a private-use code point
+#[inline]
+fn is_private_use(c: char) -> bool {
+    matches!(c, '\u{e000}'..='\u{f8ff}' | '\u{f0000}'..='\u{ffffd}' | '\u{100000}'..='\u{10fffd}')
+}
@@ -0,0 +1,116 @@
+//! Checking that the values of string literals are in Unicode Normalization Form C.
+
+use alloc::vec::Vec;
+use core::iter;
+use core::ops::Range;
+
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+use crate::escape::{escape_unicode, escape_unit, unescape_units, EscapeStyle, Unit};
+use crate::format::first_error;
+use crate::{EscapeError, Mode};
+
+/// Find the first part of a string literal whose value is not in NFC
+///
+/// Takes the contents of a string or raw string literal (without quotes) and
+/// returns the range of the first part of them whose value changes when
+/// normalized to Unicode Normalization Form C, or `None` if the value is in
+/// NFC. Parts with errors are skipped, and other kinds of literals are not
+/// checked.
+pub fn first_non_nfc(src: &str, mode: Mode) -> Option<Range<usize>> {
+    let mut found = None;
+    segments(src, mode, |range, chars, _| {
+        if found.is_none() && !is_nfc(chars) {
+            found = Some(range);
+        }
+    });
+    found
+}
+
+/// Normalize the value of a string literal to NFC
+///
+/// Takes the contents of a string or raw string literal (without quotes) and
+/// produces contents whose value is the value of `src` in Unicode
+/// Normalization Form C, as a sequence of pieces which are returned by
+/// invoking `emit`. Parts that are already in NFC are kept as they are, others
+/// are replaced by their normalized chars, which are escaped if the part had
+/// escapes. The contents of other kinds of literals are produced unchanged.
+///
+/// Fails without producing any output if `src` has errors, returning the
+/// first error with its range.
+pub fn normalize_nfc(
+    src: &str,
+    mode: Mode,
+    mut emit: impl FnMut(&str),
+) -> Result<(), (Range<usize>, EscapeError)> {
+    first_error(src, mode)?;
+
+    let mut pos = 0;
+    segments(src, mode, |range, chars, escaped| {
+        // text between segments is a string continuation
+        emit(&src[pos..range.start]);
+        pos = range.end;
+        if is_nfc(chars) {
+            emit(&src[range]);
+            return;
+        }
+        let style = EscapeStyle::default();
+        for c in chars.iter().copied().nfc() {
+            let piece = if escaped && !c.is_ascii() && !mode.is_raw() {
+                escape_unicode(c, &style)
+            } else {
+                // normalization does not produce quotes or carriage returns
+                escape_unit(Unit::Char(c), mode, &style).unwrap()
+            };
+            emit(piece.as_str());
+        }
+    });
+    emit(&src[pos..]);
+    Ok(())
+}
+
+/// Whether `chars` are in NFC
+fn is_nfc(chars: &[char]) -> bool {
+    match is_nfc_quick(chars.iter().copied()) {
+        IsNormalized::Yes => true,
+        IsNormalized::No => false,
+        IsNormalized::Maybe => chars.iter().copied().eq(chars.iter().copied().nfc()),
+    }
+}
+
+/// Split the value of a string literal into parts that are normalized
+/// independently of each other
+///
+/// Each part starts with a char that never composes with what comes before
+/// it, and is returned by invoking `callback` with its range, its chars and
+/// whether it has escapes. Produces nothing for other kinds of literals.
+fn segments(src: &str, mode: Mode, mut callback: impl FnMut(Range<usize>, &[char], bool)) {
+    if !matches!(mode, Mode::Str | Mode::RawStr) {
+        return;
+    }
+    let mut range = 0..0;
+    let mut chars = Vec::new();
+    let mut escaped = false;
+    unescape_units(src, mode, |unit_range, res| {
+        let Ok(Unit::Char(c)) = res else {
+            return;
+        };
+        let boundary =
+            canonical_combining_class(c) == 0 && is_nfc_quick(iter::once(c)) == IsNormalized::Yes;
+        if boundary && !chars.is_empty() {
+            callback(range.clone(), &chars, escaped);
+            chars.clear();
+            escaped = false;
+        }
+        if chars.is_empty() {
+            range.start = unit_range.start;
+        }
+        range.end = unit_range.end;
+        chars.push(c);
+        escaped |= !mode.is_raw() && src[unit_range].starts_with('\\');
+    });
+    if !chars.is_empty() {
+        callback(range, &chars, escaped);
+    }
+}
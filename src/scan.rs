@@ -0,0 +1,387 @@
+//! A lightweight scanner that finds the literals in a Rust source file.
+
+use core::ops::Range;
+
+use crate::{
+    check_for_errors_in_edition, closing_quote, parse_guarded_str, with_levels, Edition,
+    EscapeError, Level, LintConfig, Mode,
+};
+
+/// A char, byte, string or C string literal token found in a source file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Literal {
+    /// The kind of literal
+    pub mode: Mode,
+    /// Range of the whole token, from the prefix up to and including the
+    /// closing delimiter (a suffix, if any, is not included)
+    pub range: Range<usize>,
+    /// Range of the contents of the literal (without quotes)
+    pub contents: Range<usize>,
+}
+
+/// Find all literals in a Rust source file
+///
+/// Skips comments (including nested block comments), lifetimes and raw
+/// identifiers, and yields the terminated literal tokens in source order.
+/// Unterminated literals are not yielded.
+///
+/// NOTE: This is not a full lexer. It only knows enough of the token
+/// grammar to find literals reliably in valid code.
+pub fn literals(src: &str) -> Literals<'_> {
+    literals_in_edition(src, Edition::LATEST)
+}
+
+/// Find all literals in a Rust source file written for `edition`
+///
+/// Like [`literals`], but reserved guarded strings (`#"..."#`) are only
+/// skipped as a whole in Rust 2024 and later.
+pub fn literals_in_edition(src: &str, edition: Edition) -> Literals<'_> {
+    Literals {
+        src,
+        pos: 0,
+        edition,
+    }
+}
+
+/// Check all literals in a Rust source file for errors
+///
+/// Runs [`check_for_errors`](crate::check_for_errors) on every literal
+/// found by [`literals`] and reports errors and warnings by invoking
+/// `callback` with ranges relative to the start of `src`.
+pub fn check_literals(src: &str, callback: impl FnMut(Range<usize>, EscapeError)) {
+    check_literals_in_edition(src, Edition::LATEST, callback)
+}
+
+/// Check all literals in a Rust source file written for `edition` for errors
+///
+/// Like [`check_literals`], but runs
+/// [`check_for_errors_in_edition`](crate::check_for_errors_in_edition)
+/// on every literal, and also reports reserved prefixes (since 2021) and
+/// reserved guarded strings (since 2024). The error for a literal of a kind
+/// that does not exist in `edition` covers the whole token, prefix included.
+pub fn check_literals_in_edition(
+    src: &str,
+    edition: Edition,
+    mut callback: impl FnMut(Range<usize>, EscapeError),
+) {
+    let mut tokens = literals_in_edition(src, edition);
+    while let Some(token) = tokens.next_token() {
+        match token {
+            // the whole token, including its prefix, is unavailable
+            Token::Literal(lit) if edition < lit.mode.min_edition() => {
+                callback(lit.range, EscapeError::UnavailableInEdition)
+            }
+            Token::Literal(lit) => {
+                let offset = lit.contents.start;
+                check_for_errors_in_edition(&src[lit.contents], lit.mode, edition, |range, err| {
+                    callback(range.start + offset..range.end + offset, err)
+                });
+            }
+            Token::Error(range, err) => callback(range, err),
+        }
+    }
+}
+
+/// Check all literals in a Rust source file for errors and warnings, at the
+/// levels given by `config`
+///
+/// Like [`check_literals`], but produces each error or warning together with
+/// its level, like [`check_for_errors_with_levels`](crate::check_for_errors_with_levels).
+pub fn check_literals_with_levels(
+    src: &str,
+    config: &LintConfig,
+    callback: impl FnMut(Range<usize>, EscapeError, Level),
+) {
+    check_literals_in_edition_with_levels(src, Edition::LATEST, config, callback)
+}
+
+/// Check all literals in a Rust source file written for `edition` for errors
+/// and warnings, at the levels given by `config`
+///
+/// Like [`check_literals_in_edition`], but produces levels like
+/// [`check_literals_with_levels`].
+pub fn check_literals_in_edition_with_levels(
+    src: &str,
+    edition: Edition,
+    config: &LintConfig,
+    callback: impl FnMut(Range<usize>, EscapeError, Level),
+) {
+    check_literals_in_edition(src, edition, with_levels(config, callback))
+}
+
+/// Iterator over the literals in a source file, created by [`literals`]
+/// or [`literals_in_edition`]
+#[derive(Debug, Clone)]
+pub struct Literals<'a> {
+    src: &'a str,
+    pos: usize,
+    edition: Edition,
+}
+
+/// Token of interest found by the scanner
+enum Token {
+    Literal(Literal),
+    /// Reserved syntax
+    Error(Range<usize>, EscapeError),
+}
+
+impl Iterator for Literals<'_> {
+    type Item = Literal;
+
+    fn next(&mut self) -> Option<Literal> {
+        while let Some(token) = self.next_token() {
+            if let Token::Literal(lit) = token {
+                return Some(lit);
+            }
+        }
+        None
+    }
+}
+
+impl Literals<'_> {
+    fn next_token(&mut self) -> Option<Token> {
+        while let Some(c) = self.first() {
+            let start = self.pos;
+            self.pos += c.len_utf8();
+            let token = match c {
+                '/' if self.first() == Some('/') => {
+                    self.eat_while(|c| c != '\n');
+                    None
+                }
+                '/' if self.first() == Some('*') => {
+                    self.block_comment();
+                    None
+                }
+                '"' => self.double_quoted(start, Mode::Str),
+                '\'' => self.lifetime_or_char(start),
+                '#' if self.edition >= Edition::Edition2024
+                    && matches!(self.first(), Some('"' | '#')) =>
+                {
+                    self.guarded_str(start)
+                }
+                c if c.is_ascii_digit() => {
+                    // Skip number literals including their suffixes.
+                    self.eat_while(is_id_continue);
+                    None
+                }
+                c if is_id_start(c) => self.ident_or_prefixed(start),
+                _ => None,
+            };
+            if token.is_some() {
+                return token;
+            }
+        }
+        None
+    }
+
+    fn rest(&self) -> &str {
+        &self.src[self.pos..]
+    }
+
+    fn first(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn second(&self) -> Option<char> {
+        self.rest().chars().nth(1)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.first()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat_while(&mut self, mut predicate: impl FnMut(char) -> bool) {
+        let rest = self.rest();
+        self.pos += rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+    }
+
+    /// Skip a (possibly nested) block comment, after the leading `/*`
+    fn block_comment(&mut self) {
+        self.pos += 1;
+        let mut depth = 1usize;
+        while let Some(c) = self.bump() {
+            match c {
+                '/' if self.first() == Some('*') => {
+                    self.pos += 1;
+                    depth += 1;
+                }
+                '*' if self.first() == Some('/') => {
+                    self.pos += 1;
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Scan an identifier, a raw identifier, or a literal with a prefix
+    fn ident_or_prefixed(&mut self, start: usize) -> Option<Token> {
+        self.eat_while(is_id_continue);
+        let prefix = &self.src[start..self.pos];
+        match (prefix, self.first()) {
+            ("b", Some('\'')) => {
+                self.pos += 1;
+                self.single_quoted(start, Mode::Byte)
+            }
+            ("b", Some('"')) => {
+                self.pos += 1;
+                self.double_quoted(start, Mode::ByteStr)
+            }
+            ("c", Some('"')) => {
+                self.pos += 1;
+                self.double_quoted(start, Mode::CStr)
+            }
+            ("r", Some('#')) if self.second().is_some_and(is_id_start) => {
+                // raw identifier
+                self.pos += 1;
+                self.eat_while(is_id_continue);
+                None
+            }
+            ("r", Some('"' | '#')) => self.raw_double_quoted(start, Mode::RawStr),
+            ("br", Some('"' | '#')) => self.raw_double_quoted(start, Mode::RawByteStr),
+            ("cr", Some('"' | '#')) => self.raw_double_quoted(start, Mode::RawCStr),
+            (_, Some('"' | '\'' | '#')) if self.edition >= Edition::Edition2021 => {
+                Some(Token::Error(start..self.pos, EscapeError::ReservedPrefix))
+            }
+            _ => None,
+        }
+    }
+
+    /// Scan a reserved guarded string or multiple hashes, after the first `#`
+    fn guarded_str(&mut self, start: usize) -> Option<Token> {
+        let token = match parse_guarded_str(&self.src[start..]) {
+            Ok(guarded) => start..start + guarded.range.end,
+            Err((range, _)) => start..start + range.end,
+        };
+        self.pos = token.end;
+        Some(Token::Error(token, EscapeError::ReservedGuardedStr))
+    }
+
+    /// Scan a lifetime or a char literal, after the leading `'`
+    fn lifetime_or_char(&mut self, start: usize) -> Option<Token> {
+        let can_be_a_lifetime = self.second() != Some('\'')
+            && self
+                .first()
+                .is_some_and(|c| is_id_start(c) || c.is_ascii_digit());
+        if !can_be_a_lifetime {
+            return self.single_quoted(start, Mode::Char);
+        }
+        if self.first() == Some('r') && self.second() == Some('#') {
+            // raw lifetime
+            self.pos += 2;
+        }
+        self.eat_while(is_id_continue);
+        // A closing quote means this was a char literal with more than one char.
+        if self.first() == Some('\'') {
+            self.pos += 1;
+            return Some(Token::Literal(Literal {
+                mode: Mode::Char,
+                range: start..self.pos,
+                contents: start + 1..self.pos - 1,
+            }));
+        }
+        None
+    }
+
+    /// Scan a char or byte literal, after the opening `'`
+    fn single_quoted(&mut self, start: usize, mode: Mode) -> Option<Token> {
+        let contents_start = self.pos;
+        // Check if it's a one-symbol literal.
+        if self.second() == Some('\'') && self.first() != Some('\\') {
+            self.bump();
+            self.pos += 1;
+            return Some(Token::Literal(Literal {
+                mode,
+                range: start..self.pos,
+                contents: contents_start..self.pos - 1,
+            }));
+        }
+        loop {
+            match self.first()? {
+                '\'' => {
+                    self.pos += 1;
+                    return Some(Token::Literal(Literal {
+                        mode,
+                        range: start..self.pos,
+                        contents: contents_start..self.pos - 1,
+                    }));
+                }
+                // Probably the beginning of a comment; the literal is unterminated.
+                '/' => return None,
+                '\n' if self.second() != Some('\'') => return None,
+                '\\' => {
+                    self.bump();
+                    self.bump();
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+    }
+
+    /// Scan a non-raw string literal, after the opening `"`
+    fn double_quoted(&mut self, start: usize, mode: Mode) -> Option<Token> {
+        let contents_start = self.pos;
+        while let Some(c) = self.bump() {
+            match c {
+                '"' => {
+                    return Some(Token::Literal(Literal {
+                        mode,
+                        range: start..self.pos,
+                        contents: contents_start..self.pos - 1,
+                    }))
+                }
+                '\\' if matches!(self.first(), Some('\\' | '"')) => {
+                    self.pos += 1;
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Scan a raw string literal, starting at the hashes or opening `"`
+    fn raw_double_quoted(&mut self, start: usize, mode: Mode) -> Option<Token> {
+        let hashes_start = self.pos;
+        self.eat_while(|c| c == '#');
+        let n_hashes = self.pos - hashes_start;
+        if self.bump() != Some('"') {
+            return None;
+        }
+        if n_hashes > 255 {
+            return Some(Token::Error(
+                hashes_start..self.pos - 1,
+                EscapeError::TooManyHashes,
+            ));
+        }
+        let contents_start = self.pos;
+        let Some(len) = closing_quote(self.rest(), n_hashes) else {
+            self.pos = self.src.len();
+            return None;
+        };
+        self.pos += len + 1 + n_hashes;
+        Some(Token::Literal(Literal {
+            mode,
+            range: start..self.pos,
+            contents: contents_start..contents_start + len,
+        }))
+    }
+}
+
+/// Approximation of `XID_Start`, sufficient for skipping identifiers
+#[inline]
+fn is_id_start(c: char) -> bool {
+    c == '_' || c.is_ascii_alphabetic() || (!c.is_ascii() && c.is_alphabetic())
+}
+
+/// Approximation of `XID_Continue`, sufficient for skipping identifiers
+#[inline]
+fn is_id_continue(c: char) -> bool {
+    c == '_' || c.is_ascii_alphanumeric() || (!c.is_ascii() && c.is_alphanumeric())
+}
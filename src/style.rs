@@ -0,0 +1,112 @@
+//! Detecting the escape style of existing literals.
+
+use crate::escape::{unescape_units, EscapeStyle, Unit};
+use crate::Mode;
+
+/// Detect how the escapes in a literal are written
+///
+/// Takes the contents of a literal (without quotes) and returns the style
+/// used by most of its hex and unicode escapes, so that new escapes can be
+/// written the same way. Conventions that no escape shows are left at their
+/// default, and so are ties. Invalid escapes are ignored.
+pub fn detect_style(src: &str, mode: Mode) -> EscapeStyle {
+    let mut votes = Votes::default();
+    unescape_units(src, mode, |range, res| {
+        let Ok(unit) = res else {
+            return;
+        };
+        let piece = &src[range];
+        if let Some(digits) = piece.strip_prefix("\\x") {
+            votes.case(digits);
+            if matches!(unit, Unit::Char(_)) {
+                votes.hex_for_ascii += 1;
+            }
+        } else if let Some(inner) = piece.strip_prefix("\\u{") {
+            let inner = &inner[..inner.len() - 1];
+            votes.case(inner);
+            let c = match unit {
+                Unit::Char(c) => c,
+                Unit::Byte(_) => unreachable!("unicode escapes are chars"),
+            };
+            if c.is_ascii() {
+                votes.unicode_for_ascii += 1;
+            }
+            let n_digits = inner.bytes().filter(|&b| b != b'_').count();
+            let needed = (u32::from(c).max(1).ilog2() / 4 + 1) as usize;
+            if n_digits > needed {
+                votes.padded[n_digits] += 1;
+            } else {
+                votes.unpadded[n_digits] += 1;
+            }
+            match inner.rfind('_') {
+                Some(pos) => votes.grouped[inner.len() - pos - 1] += 1,
+                None => votes.ungrouped[n_digits] += 1,
+            }
+        }
+    });
+
+    // padding to a width changes shorter escapes, grouping longer ones
+    let unicode_min_digits = dominant(&votes.padded, &votes.unpadded, |width, n_digits| {
+        n_digits < width
+    });
+    let unicode_underscore_groups =
+        dominant(&votes.grouped, &votes.ungrouped, |group, n_digits| {
+            n_digits > group
+        });
+    EscapeStyle {
+        uppercase_hex: votes.uppercase > votes.lowercase,
+        unicode_for_ascii: votes.unicode_for_ascii > votes.hex_for_ascii,
+        unicode_min_digits,
+        unicode_underscore_groups,
+    }
+}
+
+/// Number of escapes that follow each convention
+#[derive(Default)]
+struct Votes {
+    uppercase: usize,
+    lowercase: usize,
+    hex_for_ascii: usize,
+    unicode_for_ascii: usize,
+    /// Unicode escapes with leading zeros, by number of digits
+    padded: [usize; 7],
+    /// Unicode escapes without leading zeros, by number of digits
+    unpadded: [usize; 7],
+    /// Unicode escapes with underscores, by number of digits after the last one
+    grouped: [usize; 7],
+    /// Unicode escapes without underscores, by number of digits
+    ungrouped: [usize; 7],
+}
+
+impl Votes {
+    /// Count the case of the hex digits of an escape, if it has letters
+    fn case(&mut self, digits: &str) {
+        if digits.bytes().any(|b| b.is_ascii_uppercase()) {
+            self.uppercase += 1;
+        } else if digits.bytes().any(|b| b.is_ascii_lowercase()) {
+            self.lowercase += 1;
+        }
+    }
+}
+
+/// Pick the most common size of a convention that escapes follow (`with`),
+/// unless at least as many escapes that it would change do without it
+fn dominant(with: &[usize; 7], without: &[usize; 7], changes: impl Fn(usize, usize) -> bool) -> u8 {
+    let Some((size, &count)) = with
+        .iter()
+        .enumerate()
+        .skip(1)
+        .max_by_key(|&(size, &count)| (count, core::cmp::Reverse(size)))
+    else {
+        return 0;
+    };
+    let against: usize = (1..7)
+        .filter(|&n_digits| changes(size, n_digits))
+        .map(|n_digits| without[n_digits])
+        .sum();
+    if count > against {
+        size as u8
+    } else {
+        0
+    }
+}
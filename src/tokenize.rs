@@ -0,0 +1,107 @@
+//! Splitting the contents of literals into escapes and text, e.g. for highlighting.
+
+use core::ops::Range;
+
+use crate::escape::unescape_units;
+use crate::{EscapeError, Mode, Unescape};
+
+/// A piece of the contents of a literal, produced by [`tokenize`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Piece {
+    /// Chars that stand for themselves.
+    Text,
+    /// An escape like `\n`, `\0` or `\"`.
+    SimpleEscape,
+    /// A hex escape like `\x7f`.
+    HexEscape,
+    /// A unicode escape like `\u{7f}`.
+    UnicodeEscape,
+    /// A string continuation: `\`, a newline and the whitespace that is skipped.
+    LineContinuation,
+    /// An invalid escape or char.
+    Invalid(EscapeError),
+}
+
+/// Split the contents of a literal into pieces
+///
+/// Takes the contents of a literal (without quotes) and produces a sequence of
+/// pieces that cover all of `src` in order, which are returned by invoking
+/// `callback` with their ranges. Adjacent chars that stand for themselves form
+/// a single [`Piece::Text`].
+///
+/// Errors that concern the literal as a whole ([`EscapeError::ZeroChars`] and
+/// [`EscapeError::MoreThanOneChar`] in char and byte literals) and warnings
+/// are not reported.
+pub fn tokenize(src: &str, mode: Mode, mut callback: impl FnMut(Range<usize>, Piece)) {
+    let mut text: Option<Range<usize>> = None;
+    let mut pos = 0;
+    let mut piece = |range: Range<usize>, res: Result<(), EscapeError>| {
+        // text between pieces is a string continuation
+        while pos < range.start {
+            let rest = &src[pos + 1..range.start];
+            let end = rest.find('\\').map_or(range.start, |i| pos + 1 + i);
+            flush(&mut text, &mut callback);
+            callback(pos..end, Piece::LineContinuation);
+            pos = end;
+        }
+        pos = range.end;
+        let kind = match res {
+            Err(err) => Piece::Invalid(err),
+            Ok(()) if mode.is_raw() => Piece::Text,
+            Ok(()) => match src[range.clone()].strip_prefix('\\') {
+                Some(escape) if escape.starts_with('x') => Piece::HexEscape,
+                Some(escape) if escape.starts_with('u') => Piece::UnicodeEscape,
+                Some(_) => Piece::SimpleEscape,
+                None => Piece::Text,
+            },
+        };
+        if kind == Piece::Text {
+            text = Some(
+                text.take()
+                    .map_or(range.clone(), |text| text.start..range.end),
+            );
+        } else {
+            flush(&mut text, &mut callback);
+            callback(range, kind);
+        }
+    };
+    match mode {
+        Mode::Char => single_quoted::<str>(src, &mut piece),
+        Mode::Byte => single_quoted::<[u8]>(src, &mut piece),
+        _ => unescape_units(src, mode, |range, res| match res {
+            Err(err) if !err.is_fatal() => {}
+            res => piece(range, res.map(drop)),
+        }),
+    }
+    piece(src.len()..src.len(), Ok(()));
+    flush(&mut text, &mut callback);
+}
+
+/// Report pending text
+fn flush(text: &mut Option<Range<usize>>, callback: &mut impl FnMut(Range<usize>, Piece)) {
+    if let Some(range) = text.take() {
+        if !range.is_empty() {
+            callback(range, Piece::Text);
+        }
+    }
+}
+
+/// Unescape the contents of a char or byte literal unit by unit, with the same
+/// rules as [`Unescape::unescape_single`]
+pub(crate) fn single_quoted<T: Unescape + ?Sized>(
+    src: &str,
+    callback: &mut impl FnMut(Range<usize>, Result<(), EscapeError>),
+) {
+    let mut chars = src.chars();
+    while let Some(c) = chars.next() {
+        let start = src.len() - chars.as_str().len() - c.len_utf8();
+        let res = match c {
+            '\\' => T::unescape_1(&mut chars).map(drop),
+            '\n' | '\t' | '\'' => Err(EscapeError::EscapeOnlyChar),
+            '\r' => Err(EscapeError::BareCarriageReturn),
+            c => T::char2unit(c).map(drop),
+        };
+        let end = src.len() - chars.as_str().len();
+        callback(start..end, res);
+    }
+}
@@ -1,9 +1,68 @@
 use rustc_literal_escaper::{
-    check_raw_byte_str, check_raw_str, unescape_byte, unescape_byte_str, unescape_char,
-    unescape_str, EscapeError,
+    canonicalize, check_for_errors, check_for_errors_in_edition,
+    check_for_errors_in_edition_with_levels, check_for_errors_with_levels, check_lints,
+    check_literals, check_literals_in_edition, check_literals_in_edition_with_levels,
+    check_literals_with_levels, check_raw_byte_str, check_raw_c_str, check_raw_str, convert,
+    describe_escape, describe_more_than_one_char, detect_style, edit_value, escape_byte,
+    escape_byte_str, escape_c_str, escape_char, escape_chars, escape_str, find_raw_terminator,
+    foreign_escape, join, literals, min_raw_hashes, parse_guarded_str, raw_byte_str_hashes,
+    raw_c_str_hashes, raw_str_hashes, split_at_value, tokenize, unescape_byte, unescape_byte_str,
+    unescape_c_str, unescape_char, unescape_guarded_str, unescape_str, wrap, EditError, Edition,
+    EscapeError, EscapeStyle, EscapeValue, ForeignEscapeKind, GuardedStr, InvisibleChars,
+    InvisibleClass, JoinError, Level, Lint, LintConfig, MixedUnit, Mode, MultipleChars, Piece,
+    WrapOptions,
 };
+use std::num::NonZero;
 use std::ops::Range;
 
+/// Check that the lints in the contents of a literal are `expected`, as
+/// ranges, lints and suggestions
+fn assert_lints(
+    config: &LintConfig,
+    src: &str,
+    mode: Mode,
+    expected: &[(Range<usize>, Lint, Option<&str>)],
+) {
+    let mut found = Vec::new();
+    check_lints(src, mode, config, |diag| {
+        found.push((diag.range, diag.lint, diag.suggestion))
+    });
+    let found: Vec<_> = found
+        .iter()
+        .map(|(range, lint, suggestion)| (range.clone(), *lint, suggestion.as_deref()))
+        .collect();
+    assert_eq!(found, expected);
+}
+
+/// The value of a literal as bytes, with chars encoded as UTF-8, ignoring warnings
+fn value_bytes(src: &str, mode: Mode) -> Vec<u8> {
+    fn push_char(out: &mut Vec<u8>, c: char) {
+        out.extend(c.encode_utf8(&mut [0; 4]).bytes());
+    }
+    let mut out = Vec::new();
+    match mode {
+        Mode::Char => push_char(&mut out, unescape_char(src).unwrap()),
+        Mode::Byte => out.push(unescape_byte(src).unwrap()),
+        Mode::Str => unescape_str(src, |_, res| match res {
+            Ok(c) => push_char(&mut out, c),
+            Err(e) => assert!(!e.is_fatal(), "{e:?}"),
+        }),
+        Mode::RawStr => check_raw_str(src, |_, res| push_char(&mut out, res.unwrap())),
+        Mode::ByteStr => unescape_byte_str(src, |_, res| match res {
+            Ok(b) => out.push(b),
+            Err(e) => assert!(!e.is_fatal(), "{e:?}"),
+        }),
+        Mode::RawByteStr => check_raw_byte_str(src, |_, res| out.push(res.unwrap())),
+        Mode::CStr => unescape_c_str(src, |_, res| match res {
+            Ok(MixedUnit::Char(c)) => push_char(&mut out, c.get()),
+            Ok(MixedUnit::HighByte(b)) => out.push(b.get()),
+            Err(e) => assert!(!e.is_fatal(), "{e:?}"),
+        }),
+        Mode::RawCStr => check_raw_c_str(src, |_, res| push_char(&mut out, res.unwrap().get())),
+    }
+    out
+}
+
 #[test]
 fn test_unescape_char_bad() {
     fn check(literal_text: &str, expected_error: EscapeError) {
@@ -128,6 +187,27 @@ fn test_unescape_str_warn() {
             (7..8, Ok('x')),
         ],
     );
+
+    // whitespace between the backslash and the newline
+    check(
+        "a\\ \t\n  x",
+        &[
+            (0..1, Ok('a')),
+            (2..4, Err(EscapeError::WhitespaceAfterBackslash)),
+            (7..8, Ok('x')),
+        ],
+    );
+    check(
+        "\\ \n \n",
+        &[
+            (1..2, Err(EscapeError::WhitespaceAfterBackslash)),
+            (0..5, Err(EscapeError::MultipleSkippedLinesWarning)),
+        ],
+    );
+    check(
+        "\\ x",
+        &[(0..2, Err(EscapeError::InvalidEscape)), (2..3, Ok('x'))],
+    );
 }
 
 #[test]
@@ -312,3 +392,1952 @@ fn test_unescape_raw_byte_str() {
         ],
     );
 }
+
+#[test]
+fn test_literals() {
+    fn check(src: &str, expected: &[(Mode, &str)]) {
+        let found: Vec<_> = literals(src)
+            .map(|lit| (lit.mode, &src[lit.contents]))
+            .collect();
+        assert_eq!(found, expected);
+    }
+
+    check(
+        r#"let x = 'a'; let y = b'\n'; let z = "s"; let w = b"b"; let v = c"c";"#,
+        &[
+            (Mode::Char, "a"),
+            (Mode::Byte, r"\n"),
+            (Mode::Str, "s"),
+            (Mode::ByteStr, "b"),
+            (Mode::CStr, "c"),
+        ],
+    );
+    check(
+        r###"r"a" br#"b"# cr##"c"#"##"###,
+        &[
+            (Mode::RawStr, "a"),
+            (Mode::RawByteStr, "b"),
+            (Mode::RawCStr, r##"c"#"##),
+        ],
+    );
+    check(
+        r#""a\"b" "\\""#,
+        &[(Mode::Str, r#"a\"b"#), (Mode::Str, r"\\")],
+    );
+
+    // comments, including nested block comments
+    check("// 'a' \"b\"\n'c'", &[(Mode::Char, "c")]);
+    check("/* /* \"a\" */ 'b' */ \"c\"", &[(Mode::Str, "c")]);
+
+    // lifetimes, raw lifetimes and raw identifiers
+    check("fn f<'a>(x: &'a str) -> 'b'", &[(Mode::Char, "b")]);
+    check("&'r#a T; 'c'", &[(Mode::Char, "c")]);
+    check("r#b \"s\" r#c", &[(Mode::Str, "s")]);
+    check("'ab'", &[(Mode::Char, "ab")]);
+    check("'''", &[(Mode::Char, "'")]);
+    check(r"'\''", &[(Mode::Char, r"\'")]);
+
+    // identifiers and numbers ending in prefix letters, suffixes
+    check(
+        "ab\"x\" 1b 0b1 \"y\"suffix",
+        &[(Mode::Str, "x"), (Mode::Str, "y")],
+    );
+
+    // unterminated literals
+    check("\"abc", &[]);
+    check("r#\"abc\"", &[]);
+}
+
+#[test]
+fn test_check_literals() {
+    fn check(src: &str, expected: &[(Range<usize>, EscapeError)]) {
+        let mut errors = Vec::new();
+        check_literals(src, |range, err| errors.push((range, err)));
+        assert_eq!(errors, expected);
+    }
+
+    check("fn main() { let _ = \"ok\"; }", &[]);
+    check(
+        r#"let _ = ("\z", b"\u{0}", c"\0", 'ab', br"é");"#,
+        &[
+            (10..12, EscapeError::InvalidEscape),
+            (17..22, EscapeError::UnicodeEscapeInByte),
+            (27..29, EscapeError::NulInCStr),
+            (33..35, EscapeError::MoreThanOneChar),
+            (41..43, EscapeError::NonAsciiCharInByte),
+        ],
+    );
+    check(
+        "/* \"\\z\" */ \"\\\n  \n x\"",
+        &[(12..18, EscapeError::MultipleSkippedLinesWarning)],
+    );
+}
+
+#[test]
+fn test_parse_guarded_str() {
+    fn check(src: &str, expected: Result<GuardedStr, (Range<usize>, EscapeError)>) {
+        assert_eq!(parse_guarded_str(src), expected);
+    }
+
+    check(
+        r##"#"foo"#"##,
+        Ok(GuardedStr {
+            hashes: 1,
+            contents: 2..5,
+            range: 0..7,
+        }),
+    );
+    check(
+        r###"##"a"#b"## tail"###,
+        Ok(GuardedStr {
+            hashes: 2,
+            contents: 3..7,
+            range: 0..10,
+        }),
+    );
+    check(
+        r###"#"a"##"###,
+        Ok(GuardedStr {
+            hashes: 1,
+            contents: 2..3,
+            range: 0..5,
+        }),
+    );
+
+    check(
+        r#""foo""#,
+        Err((0..0, EscapeError::InvalidGuardedStrDelimiter)),
+    );
+    check(
+        "##foo",
+        Err((0..2, EscapeError::InvalidGuardedStrDelimiter)),
+    );
+    check(
+        r##"##"foo"#"##,
+        Err((0..8, EscapeError::UnterminatedGuardedStr)),
+    );
+    let too_many = format!("{0}\"\"{0}", "#".repeat(256));
+    check(&too_many, Err((0..256, EscapeError::TooManyHashes)));
+    let max = format!("{0}\"\"{0}", "#".repeat(255));
+    assert_eq!(parse_guarded_str(&max).map(|s| s.hashes), Ok(255));
+}
+
+#[test]
+fn test_unescape_guarded_str() {
+    fn check(literal: &str, hashes: u8, expected: &[(Range<usize>, Result<char, EscapeError>)]) {
+        let mut unescaped = Vec::with_capacity(literal.len());
+        unescape_guarded_str(literal, hashes, |range, res| unescaped.push((range, res)));
+        assert_eq!(unescaped, expected);
+    }
+
+    // a backslash without hashes is literal
+    check(r"\n", 1, &[(0..1, Ok('\\')), (1..2, Ok('n'))]);
+    check(r#"""#, 1, &[(0..1, Ok('"'))]);
+    check(r"\#n", 1, &[(0..3, Ok('\n'))]);
+    check(
+        r"\#n",
+        2,
+        &[(0..1, Ok('\\')), (1..2, Ok('#')), (2..3, Ok('n'))],
+    );
+    check(r"\##u{41}", 2, &[(0..8, Ok('A'))]);
+    check(r"\#z", 1, &[(0..3, Err(EscapeError::InvalidEscape))]);
+    check(r"\#", 1, &[(0..2, Err(EscapeError::LoneSlash))]);
+    check("\r", 1, &[(0..1, Err(EscapeError::BareCarriageReturn))]);
+
+    // string continuation
+    check("a\\#\n   b", 1, &[(0..1, Ok('a')), (7..8, Ok('b'))]);
+    check(
+        "\\#\n \n b",
+        1,
+        &[
+            (0..6, Err(EscapeError::MultipleSkippedLinesWarning)),
+            (6..7, Ok('b')),
+        ],
+    );
+    check(
+        "\\# \n b",
+        1,
+        &[
+            (2..3, Err(EscapeError::WhitespaceAfterBackslash)),
+            (5..6, Ok('b')),
+        ],
+    );
+}
+
+#[test]
+fn test_check_for_errors_in_edition() {
+    fn check(src: &str, mode: Mode, edition: Edition, expected: &[(Range<usize>, EscapeError)]) {
+        let mut errors = Vec::new();
+        check_for_errors_in_edition(src, mode, edition, |range, err| errors.push((range, err)));
+        assert_eq!(errors, expected);
+    }
+
+    check("abc", Mode::CStr, Edition::Edition2021, &[]);
+    check("abc", Mode::RawCStr, Edition::Edition2024, &[]);
+    check(
+        "abc",
+        Mode::CStr,
+        Edition::Edition2018,
+        &[(0..3, EscapeError::UnavailableInEdition)],
+    );
+    check(
+        r"\0",
+        Mode::RawCStr,
+        Edition::Edition2015,
+        &[(0..2, EscapeError::UnavailableInEdition)],
+    );
+    check(r"\0", Mode::Str, Edition::Edition2015, &[]);
+    check(
+        r"\0",
+        Mode::CStr,
+        Edition::Edition2024,
+        &[(0..2, EscapeError::NulInCStr)],
+    );
+
+    assert_eq!(Mode::CStr.min_edition(), Edition::Edition2021);
+    assert_eq!(Mode::RawByteStr.min_edition(), Edition::Edition2015);
+}
+
+#[test]
+fn test_check_literals_in_edition() {
+    fn check(src: &str, edition: Edition, expected: &[(Range<usize>, EscapeError)]) {
+        let mut errors = Vec::new();
+        check_literals_in_edition(src, edition, |range, err| errors.push((range, err)));
+        assert_eq!(errors, expected);
+    }
+
+    let src = r#"(c"a", foo"b")"#;
+    check(
+        src,
+        Edition::Edition2024,
+        &[(7..10, EscapeError::ReservedPrefix)],
+    );
+    check(
+        src,
+        Edition::Edition2021,
+        &[(7..10, EscapeError::ReservedPrefix)],
+    );
+    check(
+        src,
+        Edition::Edition2018,
+        &[(1..5, EscapeError::UnavailableInEdition)],
+    );
+    check(
+        r#"x = c""; y = cr"a";"#,
+        Edition::Edition2018,
+        &[
+            (4..7, EscapeError::UnavailableInEdition),
+            (13..18, EscapeError::UnavailableInEdition),
+        ],
+    );
+
+    let src = r###"x = #"\z"#; y = ##; #[attr] r#"\z"#"###;
+    check(
+        src,
+        Edition::Edition2024,
+        &[
+            (4..10, EscapeError::ReservedGuardedStr),
+            (16..18, EscapeError::ReservedGuardedStr),
+        ],
+    );
+    check(
+        src,
+        Edition::Edition2021,
+        &[(6..8, EscapeError::InvalidEscape)],
+    );
+}
+
+#[test]
+fn test_min_raw_hashes() {
+    assert_eq!(min_raw_hashes(""), Ok(0));
+    assert_eq!(min_raw_hashes("abc#"), Ok(0));
+    assert_eq!(min_raw_hashes(r#"a"b"#), Ok(1));
+    assert_eq!(min_raw_hashes(r##"a"#b"##), Ok(2));
+    assert_eq!(min_raw_hashes(r###""## "# ""###), Ok(3));
+
+    let max = format!("\"{}", "#".repeat(254));
+    assert_eq!(min_raw_hashes(&max), Ok(255));
+    let too_many = format!("a\"{}", "#".repeat(255));
+    assert_eq!(
+        min_raw_hashes(&too_many),
+        Err((1..257, EscapeError::TooManyHashes))
+    );
+}
+
+#[test]
+fn test_find_raw_terminator() {
+    assert_eq!(find_raw_terminator("abc", 0), None);
+    assert_eq!(find_raw_terminator(r#"a"b"#, 0), Some(1..2));
+    assert_eq!(find_raw_terminator(r#"a"b"#, 1), None);
+    assert_eq!(find_raw_terminator(r##"a"b"#c"##, 1), Some(3..5));
+    assert_eq!(find_raw_terminator(r###"a"##"###, 1), Some(1..3));
+}
+
+#[test]
+fn test_raw_hashes_for_value() {
+    assert_eq!(raw_str_hashes(r#"C:\"path"#), Ok(1));
+    assert_eq!(raw_str_hashes("é"), Ok(0));
+    assert_eq!(
+        raw_str_hashes("a\rb"),
+        Err((1..2, EscapeError::BareCarriageReturnInRawString))
+    );
+
+    assert_eq!(raw_byte_str_hashes(br##""#"##), Ok(2));
+    assert_eq!(
+        raw_byte_str_hashes(b"a\xff"),
+        Err((1..2, EscapeError::NonAsciiCharInByte))
+    );
+    assert_eq!(
+        raw_byte_str_hashes(b"\r"),
+        Err((0..1, EscapeError::BareCarriageReturnInRawString))
+    );
+
+    assert_eq!(raw_c_str_hashes("é\"".as_bytes()), Ok(1));
+    assert_eq!(
+        raw_c_str_hashes(b"a\0"),
+        Err((1..2, EscapeError::NulInCStr))
+    );
+    assert_eq!(
+        raw_c_str_hashes(b"ab\xa5c"),
+        Err((2..3, EscapeError::NonUtf8HighByte))
+    );
+    assert_eq!(
+        raw_c_str_hashes(b"a\xe2\x82"),
+        Err((1..3, EscapeError::NonUtf8HighByte))
+    );
+}
+
+#[test]
+fn test_escape() {
+    fn collect(f: impl FnOnce(&mut dyn FnMut(&str))) -> String {
+        let mut out = String::new();
+        f(&mut |piece| out.push_str(piece));
+        out
+    }
+
+    assert_eq!(
+        collect(|e| escape_char('\'', &EscapeStyle::default(), e)),
+        r"\'"
+    );
+    assert_eq!(
+        collect(|e| escape_char('"', &EscapeStyle::default(), e)),
+        "\""
+    );
+    assert_eq!(
+        collect(|e| escape_char('\u{7f}', &EscapeStyle::default(), e)),
+        r"\x7f"
+    );
+    assert_eq!(
+        collect(|e| escape_char('é', &EscapeStyle::default(), e)),
+        "é"
+    );
+    assert_eq!(
+        collect(|e| escape_byte(b'\0', &EscapeStyle::default(), e)),
+        r"\0"
+    );
+    assert_eq!(
+        collect(|e| escape_byte(0xa5, &EscapeStyle::default(), e)),
+        r"\xa5"
+    );
+
+    let value = "a\"'\\\n\r\t\0\u{1b}é🦀";
+    let escaped = collect(|e| escape_str(value, &EscapeStyle::default(), e));
+    assert_eq!(escaped, r#"a\"'\\\n\r\t\0\x1bé🦀"#);
+    let mut unescaped = String::new();
+    unescape_str(&escaped, |_, res| unescaped.push(res.unwrap()));
+    assert_eq!(unescaped, value);
+
+    let value = b"a\"\\\n\x00\x7f\x80\xff";
+    let escaped = collect(|e| escape_byte_str(value, &EscapeStyle::default(), e));
+    assert_eq!(escaped, r#"a\"\\\n\0\x7f\x80\xff"#);
+    let mut unescaped = Vec::new();
+    unescape_byte_str(&escaped, |_, res| unescaped.push(res.unwrap()));
+    assert_eq!(unescaped, value);
+
+    let value = c"é\xa5\xc3";
+    let escaped = collect(|e| escape_c_str(value, &EscapeStyle::default(), e));
+    assert_eq!(escaped, r"é\xa5\xc3");
+    let mut unescaped = Vec::new();
+    unescape_c_str(&escaped, |_, res| match res.unwrap() {
+        MixedUnit::Char(c) => unescaped.extend(c.get().encode_utf8(&mut [0; 4]).bytes()),
+        MixedUnit::HighByte(b) => unescaped.push(b.get()),
+    });
+    assert_eq!(unescaped, value.to_bytes());
+}
+
+#[test]
+fn test_convert() {
+    fn check(
+        src: &str,
+        from: Mode,
+        to: Mode,
+        expected: Result<(&str, u8), (Range<usize>, EscapeError)>,
+    ) {
+        let mut out = String::new();
+        let res = convert(src, from, to, |piece| out.push_str(piece));
+        assert_eq!(res.map(|hashes| (out.as_str(), hashes)), expected);
+    }
+
+    // str <-> raw str
+    check(r#"a\"b\\"#, Mode::Str, Mode::RawStr, Ok((r#"a"b\"#, 1)));
+    check(r##"\"#"##, Mode::Str, Mode::RawStr, Ok((r##""#"##, 2)));
+    check("a\\\n  b", Mode::Str, Mode::RawStr, Ok(("ab", 0)));
+    check(r#"C:\"x"#, Mode::RawStr, Mode::Str, Ok((r#"C:\\\"x"#, 0)));
+    check(
+        r"a\rb",
+        Mode::Str,
+        Mode::RawStr,
+        Err((1..3, EscapeError::BareCarriageReturnInRawString)),
+    );
+    let many = format!("\"{}", "#".repeat(255));
+    check(
+        &many,
+        Mode::RawStr,
+        Mode::RawStr,
+        Err((255..256, EscapeError::TooManyHashes)),
+    );
+
+    // str <-> byte str
+    check(r"a\n\u{41}", Mode::Str, Mode::ByteStr, Ok((r"a\nA", 0)));
+    check(
+        r"a\u{e9}",
+        Mode::Str,
+        Mode::ByteStr,
+        Err((1..7, EscapeError::NonAsciiCharInByte)),
+    );
+    check(r"\x7f", Mode::ByteStr, Mode::Str, Ok((r"\x7f", 0)));
+    check(
+        r"\xff",
+        Mode::ByteStr,
+        Mode::Str,
+        Err((0..4, EscapeError::OutOfRangeHexEscape)),
+    );
+    check(
+        r"\xff",
+        Mode::ByteStr,
+        Mode::RawByteStr,
+        Err((0..4, EscapeError::NonAsciiCharInByte)),
+    );
+
+    // C strings
+    check(
+        r"a\0",
+        Mode::Str,
+        Mode::CStr,
+        Err((1..3, EscapeError::NulInCStr)),
+    );
+    check("é", Mode::CStr, Mode::ByteStr, Ok((r"\xc3\xa9", 0)));
+    check(r"\xc3\xa9", Mode::CStr, Mode::RawCStr, Ok(("é", 0)));
+    check(
+        r"\xc3\xa9\xa5",
+        Mode::ByteStr,
+        Mode::CStr,
+        Ok((r"\xc3\xa9\xa5", 0)),
+    );
+    check(
+        r"\xc3\xa9\xa5",
+        Mode::ByteStr,
+        Mode::RawCStr,
+        Err((8..12, EscapeError::NonUtf8HighByte)),
+    );
+    check(
+        r"\xc3a",
+        Mode::CStr,
+        Mode::RawCStr,
+        Err((0..4, EscapeError::NonUtf8HighByte)),
+    );
+    check(
+        r"\xa5",
+        Mode::CStr,
+        Mode::Str,
+        Err((0..4, EscapeError::OutOfRangeHexEscape)),
+    );
+    check("é\"", Mode::RawCStr, Mode::Str, Ok(("é\\\"", 0)));
+
+    // chars and bytes
+    check("'", Mode::RawStr, Mode::Char, Ok((r"\'", 0)));
+    check(r#"\""#, Mode::Char, Mode::Str, Ok((r#"\""#, 0)));
+    check(r"\x41", Mode::Byte, Mode::RawByteStr, Ok(("A", 0)));
+    check(
+        "",
+        Mode::Str,
+        Mode::Char,
+        Err((0..0, EscapeError::ZeroChars)),
+    );
+    check(
+        "ab",
+        Mode::Str,
+        Mode::Byte,
+        Err((1..2, EscapeError::MoreThanOneChar)),
+    );
+
+    // errors in the source
+    check(
+        r"a\z",
+        Mode::Str,
+        Mode::RawStr,
+        Err((1..3, EscapeError::InvalidEscape)),
+    );
+}
+
+#[test]
+fn test_canonicalize() {
+    fn check_with(src: &str, mode: Mode, style: &EscapeStyle, expected: &str) {
+        let mut out = String::new();
+        assert_eq!(
+            canonicalize(src, mode, style, |piece| out.push_str(piece)),
+            Ok(())
+        );
+        assert_eq!(out, expected);
+        assert_eq!(value_bytes(&out, mode), value_bytes(src, mode));
+    }
+    fn check(src: &str, mode: Mode, expected: &str) {
+        check_with(src, mode, &EscapeStyle::default(), expected)
+    }
+
+    check(r"\u{41}\x42\u{0_0_4_3}", Mode::Str, "ABC");
+    check(r#"\'\"\\"#, Mode::Str, r#"'\"\\"#);
+    check(r#"\""#, Mode::Char, "\"");
+    check(r"\x0a\u{9}\x0D\u{0}", Mode::Str, r"\n\t\r\0");
+    check(r"\x1B\u{7f}", Mode::Str, r"\x1b\x7f");
+    check(r"\u{00E9}\u{1F_980}é", Mode::Str, r"\u{e9}\u{1f980}é");
+    check(r"\xFF\x41", Mode::ByteStr, r"\xffA");
+    check(r"\xFF\u{00e9}", Mode::CStr, r"\xff\u{e9}");
+    check(r"\u{27}", Mode::Char, r"\'");
+    check(r"\x41", Mode::Byte, "A");
+    check(r"\x41\u{42}", Mode::RawStr, r"\x41\u{42}");
+
+    // continuations are kept, but must not swallow escaped whitespace
+    check("a\\\n  \\x62", Mode::Str, "a\\\n  b");
+    check("a\\\n\\x20\\x20", Mode::Str, "a\\\n\\x20 ");
+
+    let style = EscapeStyle {
+        uppercase_hex: true,
+        unicode_for_ascii: true,
+        unicode_min_digits: 4,
+        unicode_underscore_groups: 0,
+    };
+    check_with(r"\x1b\u{e9}\x41", Mode::Str, &style, r"\u{001B}\u{00E9}A");
+    check_with(r"\x1b\xff", Mode::ByteStr, &style, r"\x1B\xFF");
+    check_with(r"\u{1f980}", Mode::Str, &style, r"\u{1F980}");
+    let style = EscapeStyle {
+        unicode_underscore_groups: 2,
+        ..style
+    };
+    check_with(
+        r"\u{1f980}\u{e9}",
+        Mode::Str,
+        &style,
+        r"\u{1_F9_80}\u{00_E9}",
+    );
+
+    assert_eq!(
+        canonicalize(r"a\q", Mode::Str, &EscapeStyle::default(), |_| panic!()),
+        Err((1..3, EscapeError::InvalidEscape))
+    );
+}
+
+#[test]
+fn test_wrap() {
+    fn check(src: &str, mode: Mode, width: usize, expected: &str) {
+        let options = WrapOptions {
+            width,
+            first_column: 0,
+            indent: 2,
+            style: EscapeStyle::default(),
+        };
+        let mut out = String::new();
+        assert_eq!(
+            wrap(src, mode, &options, |piece| out.push_str(piece)),
+            Ok(())
+        );
+        assert_eq!(out, expected);
+
+        assert_eq!(value_bytes(&out, mode), value_bytes(src, mode));
+        check_for_errors(&out, mode, |range, err| {
+            panic!("{err:?} at {range:?} in {out:?}")
+        });
+    }
+
+    check("short", Mode::Str, 10, "short");
+    check(
+        "the quick brown fox",
+        Mode::Str,
+        11,
+        "the quick \\\n  brown \\\n  fox",
+    );
+    check("abcdefghij", Mode::Str, 5, "abcd\\\n  ef\\\n  gh\\\n  ij");
+    // never inside an escape
+    check(r"ab\u{1F980}cd", Mode::Str, 6, "ab\\\n  \\u{1F980}\\\n  cd");
+    check(
+        r"ab\x41\ncd",
+        Mode::ByteStr,
+        6,
+        "ab\\\n  \\x41\\\n  \\nc\\\n  d",
+    );
+    // leading whitespace is escaped
+    check("abc   d", Mode::Str, 4, "abc\\\n  \\x20 \\\n  \\x20d");
+    check("abc\td", Mode::CStr, 4, "abc\\\n  \\td");
+    check("abc\u{a0}d", Mode::Str, 4, "abc\\\n  \\u{a0}d");
+    // existing continuations are kept
+    check("ab\\\n   cdef gh", Mode::Str, 9, "ab\\\n   cdef \\\n  gh");
+    // other kinds of literals are unchanged
+    check("abcdefghij", Mode::RawStr, 5, "abcdefghij");
+
+    let options = WrapOptions {
+        width: 13,
+        first_column: 8,
+        indent: 4,
+        style: EscapeStyle::default(),
+    };
+    let mut out = String::new();
+    wrap("aaa bbb ccc", Mode::Str, &options, |piece| {
+        out.push_str(piece)
+    })
+    .unwrap();
+    assert_eq!(out, "aaa \\\n    bbb ccc");
+}
+
+#[test]
+fn test_edit_value() {
+    fn edit(src: &str, mode: Mode, value: Range<usize>, replacement: &str) -> (String, u8) {
+        let mut text = String::new();
+        let edit = edit_value(
+            src,
+            mode,
+            value.clone(),
+            replacement,
+            &EscapeStyle::default(),
+            |piece| text.push_str(piece),
+        )
+        .unwrap();
+        let mut out = src.to_string();
+        out.replace_range(edit.range, &text);
+
+        let mut expected = value_bytes(src, mode);
+        expected.splice(value, replacement.bytes());
+        assert_eq!(value_bytes(&out, mode), expected);
+        (out, edit.hashes)
+    }
+    fn check(src: &str, mode: Mode, value: Range<usize>, replacement: &str, expected: &str) {
+        assert_eq!(edit(src, mode, value, replacement).0, expected);
+    }
+
+    // only the replaced part is rewritten
+    check(r"\x66oo\tbar", Mode::Str, 0..3, "baz", r"baz\tbar");
+    check(r"\x66oo\tbar", Mode::Str, 1..3, "\"\n", r#"\x66\"\n\tbar"#);
+    check(r"a\u{e9}b", Mode::Str, 1..3, "", "ab");
+    check(
+        r"a\u{e9}b",
+        Mode::Str,
+        3..3,
+        "\u{1f980}",
+        "a\\u{e9}\u{1f980}b",
+    );
+    check("ab", Mode::Str, 2..2, "\\", r"ab\\");
+    check("", Mode::Str, 0..0, "'", "'");
+    check(r"\xffa", Mode::ByteStr, 0..1, "é", r"\xc3\xa9a");
+    check(r"\xff\u{e9}", Mode::CStr, 1..3, "x\u{1}", r"\xffx\x01");
+    check(r"\u{e9}", Mode::Char, 0..2, "'", r"\'");
+    check("a", Mode::Byte, 0..1, "\n", r"\n");
+
+    // continuations are kept, but must not swallow whitespace
+    check("a\\\n  b c", Mode::Str, 1..2, " x", "a\\\n  \\x20x c");
+    check("a\\\n  b c", Mode::Str, 1..2, "", "a\\\n  \\x20c");
+    check("a\\\n  b", Mode::Str, 1..1, "\t", "a\\\n  \\tb");
+    check("a\\\n  b", Mode::Str, 0..2, "xy", "xy");
+    check("a\\\n", Mode::Str, 1..1, " ", "a\\\n\\x20");
+
+    // raw literals report the number of `#`s they need
+    assert_eq!(
+        edit("a", Mode::RawStr, 1..1, "\"#"),
+        ("a\"#".to_string(), 2)
+    );
+    assert_eq!(edit("\"#a", Mode::RawStr, 2..3, ""), ("\"#".to_string(), 2));
+    assert_eq!(edit("\"#a", Mode::RawStr, 1..2, ""), ("\"a".to_string(), 1));
+    assert_eq!(
+        edit("ab", Mode::RawByteStr, 0..1, "\\"),
+        ("\\b".to_string(), 0)
+    );
+
+    let fail = |src: &str, mode: Mode, value: Range<usize>, replacement: &str| {
+        edit_value(
+            src,
+            mode,
+            value,
+            replacement,
+            &EscapeStyle::default(),
+            |_| panic!(),
+        )
+        .unwrap_err()
+    };
+    assert_eq!(
+        fail(r"a\q", Mode::Str, 0..1, "b"),
+        EditError::Contents(1..3, EscapeError::InvalidEscape)
+    );
+    assert_eq!(fail("é", Mode::Str, 1..2, ""), EditError::InvalidRange);
+    assert_eq!(fail("é", Mode::CStr, 0..1, ""), EditError::InvalidRange);
+    assert_eq!(fail("ab", Mode::Str, 1..3, ""), EditError::InvalidRange);
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 2..1;
+    assert_eq!(fail("ab", Mode::Str, reversed, ""), EditError::InvalidRange);
+    assert_eq!(
+        fail("ab", Mode::RawByteStr, 1..1, "é"),
+        EditError::Replacement(0..1, EscapeError::NonAsciiCharInByte)
+    );
+    assert_eq!(
+        fail("ab", Mode::RawStr, 1..1, "\r"),
+        EditError::Replacement(0..1, EscapeError::BareCarriageReturnInRawString)
+    );
+    assert_eq!(
+        fail("ab", Mode::CStr, 1..1, "\0"),
+        EditError::Replacement(0..1, EscapeError::NulInCStr)
+    );
+    assert_eq!(
+        fail("a", Mode::Char, 1..1, "b"),
+        EditError::Replacement(0..1, EscapeError::MoreThanOneChar)
+    );
+    assert_eq!(
+        fail("a", Mode::Char, 0..1, ""),
+        EditError::Replacement(0..0, EscapeError::ZeroChars)
+    );
+}
+
+#[test]
+fn test_detect_style() {
+    fn check(src: &str, mode: Mode, expected: EscapeStyle) {
+        let style = detect_style(src, mode);
+        assert_eq!(style, expected);
+        // canonical escapes keep the detected style
+        let mut out = String::new();
+        canonicalize(src, mode, &style, |piece| out.push_str(piece)).unwrap();
+        assert_eq!(detect_style(&out, mode), style);
+    }
+
+    check("abc", Mode::Str, EscapeStyle::default());
+    check(
+        r"\xFF\x0A\xab",
+        Mode::ByteStr,
+        EscapeStyle {
+            uppercase_hex: true,
+            ..EscapeStyle::default()
+        },
+    );
+    check(
+        r"\u{1B}\u{7F}\x01",
+        Mode::Str,
+        EscapeStyle {
+            uppercase_hex: true,
+            unicode_for_ascii: true,
+            ..EscapeStyle::default()
+        },
+    );
+    check(r"\u{1b}\x01", Mode::Str, EscapeStyle::default());
+    check(
+        r"\u{00e9}\u{0100}\u{1f980}",
+        Mode::Str,
+        EscapeStyle {
+            unicode_min_digits: 4,
+            ..EscapeStyle::default()
+        },
+    );
+    check(r"\u{00e9}\u{e9}\u{e9}", Mode::Str, EscapeStyle::default());
+    check(
+        r"\u{1_f980}\u{10_0000}\u{e9}",
+        Mode::CStr,
+        EscapeStyle {
+            unicode_underscore_groups: 4,
+            ..EscapeStyle::default()
+        },
+    );
+    check(r"\u{1_f980}\u{1f980}", Mode::CStr, EscapeStyle::default());
+    check(r"\u{E9}", Mode::RawStr, EscapeStyle::default());
+
+    // invalid escapes are ignored
+    assert_eq!(
+        detect_style(r"\u{E9}\xZZ\u{_E9}", Mode::Str),
+        EscapeStyle {
+            uppercase_hex: true,
+            ..EscapeStyle::default()
+        }
+    );
+}
+
+#[test]
+fn test_split_at_value() {
+    fn check(src: &str, mode: Mode, pos: usize, expected: (&str, &str)) {
+        let (left, right) = split_at_value(src, mode, pos).unwrap();
+        assert_eq!((left, right), expected);
+        let mut value = value_bytes(left, mode);
+        assert_eq!(value.len(), pos);
+        value.extend(value_bytes(right, mode));
+        assert_eq!(value, value_bytes(src, mode));
+    }
+
+    check(r"ab\ncd", Mode::Str, 2, ("ab", r"\ncd"));
+    check(r"ab\ncd", Mode::Str, 3, (r"ab\n", "cd"));
+    check(r"\u{e9}\u{e9}", Mode::Str, 2, (r"\u{e9}", r"\u{e9}"));
+    check("ab", Mode::Str, 0, ("", "ab"));
+    check("ab", Mode::Str, 2, ("ab", ""));
+    check(r"\xff\u{1f980}", Mode::CStr, 1, (r"\xff", r"\u{1f980}"));
+    check(r##"a"#b"##, Mode::RawStr, 2, (r#"a""#, "#b"));
+    check(r"\x00\x01", Mode::ByteStr, 1, (r"\x00", r"\x01"));
+
+    // continuations at the cut are dropped
+    check("ab\\\n  cd", Mode::Str, 2, ("ab", "cd"));
+    check("ab\\\n  cd", Mode::Str, 1, ("a", "b\\\n  cd"));
+    check("\\\n  ab", Mode::Str, 0, ("", "ab"));
+    check("ab\\\n", Mode::Str, 2, ("ab", ""));
+
+    assert_eq!(
+        split_at_value(r"\u{e9}", Mode::Str, 1),
+        Err(EditError::InvalidRange)
+    );
+    assert_eq!(
+        split_at_value(r"\u{e9}", Mode::CStr, 1),
+        Err(EditError::InvalidRange)
+    );
+    assert_eq!(
+        split_at_value("ab", Mode::Str, 3),
+        Err(EditError::InvalidRange)
+    );
+    assert_eq!(
+        split_at_value("a", Mode::Char, 0),
+        Err(EditError::InvalidRange)
+    );
+    assert_eq!(
+        split_at_value(r"a\q", Mode::Str, 1),
+        Err(EditError::Contents(1..3, EscapeError::InvalidEscape))
+    );
+}
+
+#[test]
+fn test_join() {
+    fn check(
+        first: (&str, Mode),
+        second: (&str, Mode),
+        expected: &str,
+        expected_mode: Mode,
+        expected_hashes: u8,
+    ) {
+        let mut out = String::new();
+        let res = join(first.0, first.1, second.0, second.1, |piece| {
+            out.push_str(piece)
+        });
+        assert_eq!(res, Ok((expected_mode, expected_hashes)));
+        assert_eq!(out, expected);
+        let mut value = value_bytes(first.0, first.1);
+        value.extend(value_bytes(second.0, second.1));
+        assert_eq!(value_bytes(&out, expected_mode), value);
+    }
+
+    check(("ab", Mode::Str), (r"\n", Mode::Str), r"ab\n", Mode::Str, 0);
+    check(
+        ("a\"", Mode::RawStr),
+        ("#b", Mode::RawStr),
+        "a\"#b",
+        Mode::RawStr,
+        2,
+    );
+    check(
+        ("a", Mode::RawStr),
+        ("b", Mode::RawStr),
+        "ab",
+        Mode::RawStr,
+        0,
+    );
+    check(
+        (r"\n", Mode::Str),
+        (r#"\""#, Mode::RawStr),
+        r#"\n\\\""#,
+        Mode::Str,
+        0,
+    );
+    check(
+        (r"a\", Mode::RawByteStr),
+        (r"\xff", Mode::ByteStr),
+        r"a\\\xff",
+        Mode::ByteStr,
+        0,
+    );
+    check(
+        ("é", Mode::RawCStr),
+        (r"\xff", Mode::CStr),
+        r"é\xff",
+        Mode::CStr,
+        0,
+    );
+
+    // whitespace after a continuation is escaped
+    check(
+        ("a\\\n", Mode::Str),
+        (" b", Mode::Str),
+        "a\\\n\\x20b",
+        Mode::Str,
+        0,
+    );
+    check(
+        ("a\\\n", Mode::Str),
+        ("\tb", Mode::RawStr),
+        "a\\\n\\tb",
+        Mode::Str,
+        0,
+    );
+    check(("a\\\n", Mode::Str), ("", Mode::Str), "a\\\n", Mode::Str, 0);
+
+    let fail = |first: (&str, Mode), second: (&str, Mode)| {
+        join(first.0, first.1, second.0, second.1, |_| panic!()).unwrap_err()
+    };
+    assert_eq!(
+        fail(("a", Mode::Str), ("b", Mode::ByteStr)),
+        JoinError::IncompatibleModes
+    );
+    assert_eq!(
+        fail(("a", Mode::Char), ("b", Mode::Char)),
+        JoinError::IncompatibleModes
+    );
+    assert_eq!(
+        fail((r"\q", Mode::Str), ("b", Mode::Str)),
+        JoinError::First(0..2, EscapeError::InvalidEscape)
+    );
+    assert_eq!(
+        fail(("a", Mode::CStr), ("\0", Mode::RawCStr)),
+        JoinError::Second(0..1, EscapeError::NulInCStr)
+    );
+}
+
+#[test]
+fn test_tokenize() {
+    fn check(src: &str, mode: Mode, expected: &[(Range<usize>, Piece)]) {
+        let mut pieces = Vec::new();
+        tokenize(src, mode, |range, piece| pieces.push((range, piece)));
+        assert_eq!(pieces, expected);
+        // the pieces cover all of `src`
+        let mut pos = 0;
+        for (range, _) in &pieces {
+            assert_eq!(range.start, pos);
+            assert!(range.end > range.start);
+            pos = range.end;
+        }
+        assert_eq!(pos, src.len());
+    }
+
+    check("", Mode::Str, &[]);
+    check(
+        r"ab\n\x7f\u{e9}é",
+        Mode::Str,
+        &[
+            (0..2, Piece::Text),
+            (2..4, Piece::SimpleEscape),
+            (4..8, Piece::HexEscape),
+            (8..14, Piece::UnicodeEscape),
+            (14..16, Piece::Text),
+        ],
+    );
+    check(
+        r"a\qb\x",
+        Mode::Str,
+        &[
+            (0..1, Piece::Text),
+            (1..3, Piece::Invalid(EscapeError::InvalidEscape)),
+            (3..4, Piece::Text),
+            (4..6, Piece::Invalid(EscapeError::TooShortHexEscape)),
+        ],
+    );
+    check(
+        "a\\\n  \\\n\nb\\\n",
+        Mode::Str,
+        &[
+            (0..1, Piece::Text),
+            (1..5, Piece::LineContinuation),
+            (5..8, Piece::LineContinuation),
+            (8..9, Piece::Text),
+            (9..11, Piece::LineContinuation),
+        ],
+    );
+    check(
+        r"\xff\u{e9}",
+        Mode::ByteStr,
+        &[
+            (0..4, Piece::HexEscape),
+            (4..10, Piece::Invalid(EscapeError::UnicodeEscapeInByte)),
+        ],
+    );
+    check(
+        r"a\0",
+        Mode::CStr,
+        &[
+            (0..1, Piece::Text),
+            (1..3, Piece::Invalid(EscapeError::NulInCStr)),
+        ],
+    );
+    check(
+        "a\\n\rb",
+        Mode::RawStr,
+        &[
+            (0..3, Piece::Text),
+            (
+                3..4,
+                Piece::Invalid(EscapeError::BareCarriageReturnInRawString),
+            ),
+            (4..5, Piece::Text),
+        ],
+    );
+    check(r"\'", Mode::Char, &[(0..2, Piece::SimpleEscape)]);
+    check(
+        "ab'",
+        Mode::Char,
+        &[
+            (0..2, Piece::Text),
+            (2..3, Piece::Invalid(EscapeError::EscapeOnlyChar)),
+        ],
+    );
+    check(
+        r"é\x80",
+        Mode::Byte,
+        &[
+            (0..2, Piece::Invalid(EscapeError::NonAsciiCharInByte)),
+            (2..6, Piece::HexEscape),
+        ],
+    );
+}
+
+#[test]
+fn test_describe_escape() {
+    let describe = |src: &str, mode: Mode, offset: usize| {
+        let desc = describe_escape(src, mode, offset)?;
+        Some((desc.range, desc.piece, desc.value))
+    };
+
+    assert_eq!(
+        describe(r"a\u{1F980}b", Mode::Str, 3),
+        Some((
+            1..10,
+            Piece::UnicodeEscape,
+            Some(EscapeValue::Char('\u{1f980}'))
+        ))
+    );
+    assert_eq!(
+        describe(r"\xA5", Mode::CStr, 0),
+        Some((0..4, Piece::HexEscape, Some(EscapeValue::Byte(0xa5))))
+    );
+    assert_eq!(
+        describe(r"\x41", Mode::ByteStr, 3),
+        Some((0..4, Piece::HexEscape, Some(EscapeValue::Byte(b'A'))))
+    );
+    assert_eq!(
+        describe(r"\n", Mode::Char, 1),
+        Some((0..2, Piece::SimpleEscape, Some(EscapeValue::Char('\n'))))
+    );
+    assert_eq!(
+        describe(r"\q", Mode::Str, 0),
+        Some((0..2, Piece::Invalid(EscapeError::InvalidEscape), None))
+    );
+    assert_eq!(describe(r"a\n", Mode::Str, 0), None);
+    assert_eq!(describe(r"\n", Mode::Str, 2), None);
+    assert_eq!(describe(r"\n", Mode::RawStr, 0), None);
+    assert_eq!(describe("\\\n  a", Mode::Str, 0), None);
+
+    let crab = EscapeValue::Char('\u{1f980}');
+    assert_eq!(crab.to_string(), "U+1F980");
+    assert_eq!(crab.encode(&mut [0; 4]), b"\xf0\x9f\xa6\x80");
+    assert_eq!(
+        crab.to_mixed_unit(),
+        Some(MixedUnit::Char(NonZero::new('\u{1f980}').unwrap()))
+    );
+    let yen = EscapeValue::Byte(0xa5);
+    assert_eq!(yen.to_string(), "0xA5");
+    assert_eq!(yen.encode(&mut [0; 4]), b"\xa5");
+    assert_eq!(
+        yen.to_mixed_unit(),
+        Some(MixedUnit::HighByte(NonZero::new(0xa5).unwrap()))
+    );
+    assert_eq!(
+        EscapeValue::Byte(b'A').to_mixed_unit(),
+        Some(MixedUnit::Char(NonZero::new('A').unwrap()))
+    );
+    assert_eq!(EscapeValue::Char('\0').to_mixed_unit(), None);
+
+    #[cfg(feature = "unicode-names")]
+    {
+        assert_eq!(crab.name().unwrap().to_string(), "CRAB");
+        assert!(EscapeValue::Char('\n').name().is_none());
+        assert!(yen.name().is_none());
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_incremental_checker() {
+    use rustc_literal_escaper::IncrementalChecker;
+
+    fn errors(src: &str, mode: Mode) -> Vec<(Range<usize>, EscapeError)> {
+        let mut errors = Vec::new();
+        check_for_errors(src, mode, |range, err| errors.push((range, err)));
+        errors
+    }
+
+    // a long literal with an error at the start and at the end
+    let mut src = String::from(r"\q");
+    src.push_str(&"abc\\n\\u{e9} \\\n  ".repeat(100));
+    src.push_str(r"\x");
+    let mut checker = IncrementalChecker::new(&src, Mode::Str);
+    assert_eq!(checker.errors(), errors(&src, Mode::Str));
+
+    // only the part around the edit is rescanned
+    let pos = src.len() / 2;
+    src.insert_str(pos, r"\z");
+    let rescanned = checker.edit(&src, pos..pos, 2);
+    assert!(rescanned.start <= pos && rescanned.end >= pos + 2);
+    assert!(rescanned.len() < 1000, "{rescanned:?}");
+    assert_eq!(checker.errors(), errors(&src, Mode::Str));
+    src.replace_range(pos..pos + 2, "");
+    checker.edit(&src, pos..pos + 2, 0);
+    assert_eq!(checker.errors(), errors(&src, Mode::Str));
+
+    // whether `\` followed by spaces starts a continuation depends on a
+    // newline after them, which can be after a position to resume at
+    let mut src = "a".repeat(62) + "\\  x";
+    let mut checker = IncrementalChecker::new(&src, Mode::Str);
+    src.insert(65, '\n');
+    checker.edit(&src, 65..65, 1);
+    assert_eq!(checker.errors(), errors(&src, Mode::Str));
+    assert_eq!(
+        checker.errors(),
+        [(63..65, EscapeError::WhitespaceAfterBackslash)]
+    );
+
+    // random edits of random literals
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    let mut random = |n: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed as usize % n
+    };
+    let pieces = [
+        "a",
+        "\\",
+        "x",
+        "u",
+        "{",
+        "}",
+        "1",
+        "f",
+        "_",
+        "0",
+        "\n",
+        " ",
+        "\t",
+        "\"",
+        "\r",
+        "é",
+        "\u{a0}",
+        "\\\n",
+        "\\ \n",
+        "\\  ",
+        "\\x7f",
+        "\\u{1f980}",
+    ];
+    for mode in [
+        Mode::Str,
+        Mode::ByteStr,
+        Mode::CStr,
+        Mode::RawStr,
+        Mode::Char,
+    ] {
+        for _ in 0..20 {
+            let mut src = String::new();
+            for _ in 0..random(2000) {
+                src.push_str(pieces[random(pieces.len())]);
+            }
+            let mut checker = IncrementalChecker::new(&src, mode);
+            for _ in 0..200 {
+                let boundaries: Vec<usize> = src
+                    .char_indices()
+                    .map(|(i, _)| i)
+                    .chain([src.len()])
+                    .collect();
+                let start = boundaries[random(boundaries.len())];
+                let end = boundaries[random(boundaries.len())]
+                    .max(start)
+                    .min(start + 30);
+                let mut end = end;
+                while !src.is_char_boundary(end) {
+                    end += 1;
+                }
+                let mut new = String::new();
+                for _ in 0..random(8) {
+                    new.push_str(pieces[random(pieces.len())]);
+                }
+                src.replace_range(start..end, &new);
+                checker.edit(&src, start..end, new.len());
+                assert_eq!(checker.errors(), errors(&src, mode), "{src:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_check_lints_text_direction() {
+    let config = LintConfig {
+        text_direction_codepoint: Level::Warn,
+        ..LintConfig::default()
+    };
+
+    assert_lints(
+        &config,
+        "a\u{202e}b\u{2066}",
+        Mode::Str,
+        &[
+            (1..4, Lint::TextDirectionCodepoint, Some(r"\u{202e}")),
+            (5..8, Lint::TextDirectionCodepoint, Some(r"\u{2066}")),
+        ],
+    );
+    assert_lints(
+        &config,
+        r"a\u{202E}",
+        Mode::Str,
+        &[(1..9, Lint::TextDirectionCodepoint, None)],
+    );
+    assert_lints(
+        &config,
+        "\u{2069}",
+        Mode::Char,
+        &[(0..3, Lint::TextDirectionCodepoint, Some(r"\u{2069}"))],
+    );
+    assert_lints(
+        &config,
+        "\u{202a}",
+        Mode::CStr,
+        &[(0..3, Lint::TextDirectionCodepoint, Some(r"\u{202a}"))],
+    );
+    assert_lints(
+        &config,
+        "\u{202a}",
+        Mode::RawStr,
+        &[(0..3, Lint::TextDirectionCodepoint, None)],
+    );
+    assert_lints(&config, "\u{2065}\u{202f}\u{200f}", Mode::Str, &[]);
+    // errors are skipped
+    assert_lints(
+        &config,
+        "\\q\u{202a}",
+        Mode::Str,
+        &[(2..5, Lint::TextDirectionCodepoint, Some(r"\u{202a}"))],
+    );
+
+    // escaped chars are reported as such
+    let mut escaped = Vec::new();
+    check_lints("\u{202e}\\u{202e}", Mode::Str, &config, |diag| {
+        escaped.push(diag.escaped)
+    });
+    assert_eq!(escaped, [false, true]);
+
+    // lints are off by default
+    check_lints("\u{202e}", Mode::Str, &LintConfig::default(), |_| panic!());
+}
+
+#[test]
+fn test_check_lints_invisible_char() {
+    let all = LintConfig {
+        invisible_char: InvisibleChars::all(Level::Warn),
+        ..LintConfig::default()
+    };
+
+    assert_lints(
+        &all,
+        "key\u{200b}\u{ad}\u{feff}\u{fe0f}",
+        Mode::Str,
+        &[
+            (
+                3..6,
+                Lint::InvisibleChar(InvisibleClass::ZeroWidth),
+                Some(r"\u{200b}"),
+            ),
+            (
+                6..8,
+                Lint::InvisibleChar(InvisibleClass::SoftHyphen),
+                Some(r"\u{ad}"),
+            ),
+            (
+                8..11,
+                Lint::InvisibleChar(InvisibleClass::ByteOrderMark),
+                Some(r"\u{feff}"),
+            ),
+            (
+                11..14,
+                Lint::InvisibleChar(InvisibleClass::DefaultIgnorable),
+                Some(r"\u{fe0f}"),
+            ),
+        ],
+    );
+    assert_lints(
+        &all,
+        "\u{200d}",
+        Mode::RawStr,
+        &[(0..3, Lint::InvisibleChar(InvisibleClass::ZeroWidth), None)],
+    );
+    assert_lints(
+        &all,
+        "\u{e0041}",
+        Mode::CStr,
+        &[(
+            0..4,
+            Lint::InvisibleChar(InvisibleClass::DefaultIgnorable),
+            Some(r"\u{e0041}"),
+        )],
+    );
+    // only the configured classes are reported
+    let soft_hyphen = LintConfig {
+        invisible_char: InvisibleChars {
+            soft_hyphen: Level::Warn,
+            ..InvisibleChars::default()
+        },
+        ..LintConfig::default()
+    };
+    assert_lints(
+        &soft_hyphen,
+        "\u{200b}\u{ad}",
+        Mode::Str,
+        &[(
+            3..5,
+            Lint::InvisibleChar(InvisibleClass::SoftHyphen),
+            Some(r"\u{ad}"),
+        )],
+    );
+    // escaped invisible chars and visible chars are fine
+    assert_lints(&all, r"\u{200b}\u{ad}", Mode::Str, &[]);
+    assert_lints(&all, "a b\u{a0}é\u{202e}", Mode::Str, &[]);
+}
+
+#[cfg(feature = "confusables")]
+#[test]
+fn test_check_lints_confusable_char() {
+    let config = LintConfig {
+        confusable_char: Level::Warn,
+        ..LintConfig::default()
+    };
+
+    assert_lints(
+        &config,
+        "\u{2010}",
+        Mode::Char,
+        &[(0..3, Lint::ConfusableChar('-'), Some("-"))],
+    );
+    assert_lints(
+        &config,
+        "k\u{435}y",
+        Mode::Str,
+        &[(1..3, Lint::ConfusableChar('e'), Some("e"))],
+    );
+    assert_lints(
+        &config,
+        "\u{2216}",
+        Mode::Str,
+        &[(0..3, Lint::ConfusableChar('\\'), Some(r"\\"))],
+    );
+    assert_lints(
+        &config,
+        "\u{2216}",
+        Mode::RawStr,
+        &[(0..3, Lint::ConfusableChar('\\'), Some(r"\"))],
+    );
+    assert_lints(
+        &config,
+        "\u{2032}",
+        Mode::Char,
+        &[(0..3, Lint::ConfusableChar('\''), Some(r"\'"))],
+    );
+    // escaped chars, other kinds of literals and other chars are fine
+    assert_lints(&config, r"\u{2010}", Mode::Str, &[]);
+    assert_lints(&config, "\u{2010}", Mode::CStr, &[]);
+    assert_lints(&config, "é\u{1f980}ß", Mode::Str, &[]);
+}
+
+#[test]
+fn test_check_lints_escape_style() {
+    let config = LintConfig {
+        redundant_escape: Level::Warn,
+        unicode_escape_leading_zeros: Level::Warn,
+        unicode_escape_underscores: Level::Warn,
+        mixed_case_hex: Level::Warn,
+        escaped_printable_ascii: Level::Warn,
+        ..LintConfig::default()
+    };
+
+    assert_lints(
+        &config,
+        r"it\'s",
+        Mode::Str,
+        &[(2..4, Lint::RedundantEscape, Some("'"))],
+    );
+    assert_lints(
+        &config,
+        r"\'",
+        Mode::CStr,
+        &[(0..2, Lint::RedundantEscape, Some("'"))],
+    );
+    assert_lints(
+        &config,
+        r#"\""#,
+        Mode::Char,
+        &[(0..2, Lint::RedundantEscape, Some("\""))],
+    );
+    assert_lints(
+        &config,
+        r#"\""#,
+        Mode::Byte,
+        &[(0..2, Lint::RedundantEscape, Some("\""))],
+    );
+    assert_lints(&config, r"\'", Mode::Char, &[]);
+    assert_lints(&config, r#"\"\n\\\0"#, Mode::Str, &[]);
+
+    assert_lints(
+        &config,
+        r"\x41",
+        Mode::Byte,
+        &[(0..4, Lint::EscapedPrintableAscii, Some("A"))],
+    );
+    assert_lints(
+        &config,
+        r"a\u{22}",
+        Mode::Str,
+        &[(1..7, Lint::EscapedPrintableAscii, Some(r#"\""#))],
+    );
+    assert_lints(
+        &config,
+        r"\x20\x7f\u{e9}",
+        Mode::Str,
+        &[(0..4, Lint::EscapedPrintableAscii, Some(" "))],
+    );
+    // whitespace after a string continuation has to be escaped
+    assert_lints(&config, "\\\n  \\x20", Mode::Str, &[]);
+    assert_lints(&config, r"\x80\xff", Mode::ByteStr, &[]);
+
+    assert_lints(
+        &config,
+        r"\u{00e9}",
+        Mode::Str,
+        &[(0..8, Lint::UnicodeEscapeLeadingZeros, Some(r"\u{e9}"))],
+    );
+    assert_lints(
+        &config,
+        r"\u{0_0}",
+        Mode::Str,
+        &[
+            (0..7, Lint::UnicodeEscapeLeadingZeros, Some(r"\u{0}")),
+            (0..7, Lint::UnicodeEscapeUnderscores, Some(r"\u{00}")),
+        ],
+    );
+    assert_lints(
+        &config,
+        r"\u{1_f980}",
+        Mode::CStr,
+        &[(0..10, Lint::UnicodeEscapeUnderscores, Some(r"\u{1f980}"))],
+    );
+    assert_lints(
+        &config,
+        r"\u{1F98a}\xFf",
+        Mode::CStr,
+        &[
+            (0..9, Lint::MixedCaseHex, Some(r"\u{1f98a}")),
+            (9..13, Lint::MixedCaseHex, Some(r"\xff")),
+        ],
+    );
+    assert_lints(
+        &config,
+        r"\u{0___________________________00e9}",
+        Mode::Str,
+        &[
+            (0..36, Lint::UnicodeEscapeLeadingZeros, Some(r"\u{e9}")),
+            (0..36, Lint::UnicodeEscapeUnderscores, Some(r"\u{000e9}")),
+        ],
+    );
+    assert_lints(&config, r"\u{1F98A}\u{0}\u{e9}\x7F\xaB", Mode::Str, &[]);
+    assert_lints(
+        &config,
+        r"\xaB",
+        Mode::ByteStr,
+        &[(0..4, Lint::MixedCaseHex, Some(r"\xab"))],
+    );
+    // raw strings have no escapes
+    assert_lints(&config, r"\'\u{0041}", Mode::RawStr, &[]);
+}
+
+#[test]
+fn test_foreign_escape() {
+    fn check(
+        src: &str,
+        mode: Mode,
+        expected: Option<(Range<usize>, ForeignEscapeKind, Option<&str>)>,
+    ) {
+        let mut errors = Vec::new();
+        check_for_errors(src, mode, |range, err| errors.push((range, err)));
+        let (range, err) = &errors[0];
+        let found = foreign_escape(src, mode, range.clone(), err);
+        let found = found
+            .as_ref()
+            .map(|esc| (esc.range.clone(), esc.kind, esc.suggestion.as_deref()));
+        assert_eq!(found, expected);
+    }
+
+    check(
+        r"hi \U0001F600",
+        Mode::Str,
+        Some((3..13, ForeignEscapeKind::LongUnicode, Some(r"\u{1F600}"))),
+    );
+    check(
+        r"\U00110000",
+        Mode::Str,
+        Some((0..10, ForeignEscapeKind::LongUnicode, None)),
+    );
+    check(
+        r"caf\u00e9!",
+        Mode::Str,
+        Some((3..9, ForeignEscapeKind::BracelessUnicode, Some(r"\u{e9}"))),
+    );
+    check(
+        r"\u0041",
+        Mode::ByteStr,
+        Some((0..6, ForeignEscapeKind::BracelessUnicode, Some(r"\x41"))),
+    );
+    check(
+        r"\u00e9",
+        Mode::ByteStr,
+        Some((0..6, ForeignEscapeKind::BracelessUnicode, None)),
+    );
+    check(
+        r"\ud83d\ude00",
+        Mode::Str,
+        Some((0..12, ForeignEscapeKind::SurrogatePair, Some(r"\u{1f600}"))),
+    );
+    // the low half of a pair is covered by the escape of the high half
+    let src = r"\ud83d\ude00";
+    let mut errors = Vec::new();
+    check_for_errors(src, Mode::Str, |range, err| errors.push((range, err)));
+    let (range, err) = &errors[1];
+    assert_eq!(foreign_escape(src, Mode::Str, range.clone(), err), None);
+    check(
+        r"\\ud83d\ude00",
+        Mode::Str,
+        Some((7..13, ForeignEscapeKind::BracelessUnicode, None)),
+    );
+    check(
+        r"\ud83d",
+        Mode::Char,
+        Some((0..6, ForeignEscapeKind::BracelessUnicode, None)),
+    );
+    check(
+        r"\a",
+        Mode::Char,
+        Some((0..2, ForeignEscapeKind::ControlChar(0x07), Some(r"\x07"))),
+    );
+    check(
+        r"\e[0m",
+        Mode::ByteStr,
+        Some((0..2, ForeignEscapeKind::ControlChar(0x1b), Some(r"\x1b"))),
+    );
+    check(
+        r"\v\f",
+        Mode::CStr,
+        Some((0..2, ForeignEscapeKind::ControlChar(0x0b), Some(r"\x0b"))),
+    );
+    check(
+        r"a\12b",
+        Mode::Str,
+        Some((1..4, ForeignEscapeKind::Octal, Some(r"\x0a"))),
+    );
+    check(
+        r"\3518",
+        Mode::Str,
+        Some((0..4, ForeignEscapeKind::Octal, Some(r"\u{e9}"))),
+    );
+    check(
+        r"\351",
+        Mode::ByteStr,
+        Some((0..4, ForeignEscapeKind::Octal, Some(r"\xe9"))),
+    );
+    check(
+        r"\777",
+        Mode::ByteStr,
+        Some((0..4, ForeignEscapeKind::Octal, None)),
+    );
+    check(
+        r"\012",
+        Mode::CStr,
+        Some((0..4, ForeignEscapeKind::Octal, Some(r"\x0a"))),
+    );
+    check(
+        r"\00",
+        Mode::CStr,
+        Some((0..3, ForeignEscapeKind::Octal, None)),
+    );
+    check(r"\0", Mode::CStr, None);
+    check(r"\0x", Mode::CStr, None);
+    check(r"\09", Mode::CStr, None);
+    #[cfg(feature = "unicode-names")]
+    let snowman = Some(r"\u{2603}");
+    #[cfg(not(feature = "unicode-names"))]
+    let snowman = None;
+    check(
+        r"\N{snowman}",
+        Mode::Str,
+        Some((0..11, ForeignEscapeKind::NamedUnicode, snowman)),
+    );
+    check(
+        r"\N{NOT A NAME}",
+        Mode::Str,
+        Some((0..14, ForeignEscapeKind::NamedUnicode, None)),
+    );
+
+    // other errors
+    check(r"\U0001F60", Mode::Str, None);
+    check(r"\u{e9", Mode::Str, None);
+    check(r"\u00g9", Mode::Str, None);
+    check(r"\N{", Mode::Str, None);
+    check(r"\8", Mode::Str, None);
+    check(r"\z", Mode::Str, None);
+    check(r"\x", Mode::CStr, None);
+    assert!(ForeignEscapeKind::Octal.explanation().contains("octal"));
+}
+
+#[test]
+fn test_check_lints_nul_followed_by_digit() {
+    let config = LintConfig {
+        nul_followed_by_digit: Level::Warn,
+        ..LintConfig::default()
+    };
+
+    assert_lints(
+        &config,
+        r"\012",
+        Mode::Str,
+        &[(0..2, Lint::NulFollowedByDigit(0o12), Some(r"\x00"))],
+    );
+    assert_lints(
+        &config,
+        r"a\0777",
+        Mode::ByteStr,
+        &[(1..3, Lint::NulFollowedByDigit(0o77), Some(r"\x00"))],
+    );
+    // `\08` and `\09` are a nul followed by a digit in C too
+    assert_lints(&config, r"\09", Mode::Str, &[]);
+    assert_lints(
+        &config,
+        r"\0\0\01",
+        Mode::Str,
+        &[(4..6, Lint::NulFollowedByDigit(1), Some(r"\x00"))],
+    );
+    assert_lints(&config, r"\0 12\x001\0", Mode::Str, &[]);
+    assert_lints(&config, r"\012", Mode::RawStr, &[]);
+    // an error in C string literals
+    assert_lints(&config, r"\012", Mode::CStr, &[]);
+}
+
+#[test]
+fn test_describe_more_than_one_char() {
+    fn check(src: &str, mode: Mode, expected: Option<(usize, bool)>) {
+        let found = describe_more_than_one_char(src, mode);
+        let expected = expected.map(|(chars, single_grapheme)| MultipleChars {
+            chars,
+            single_grapheme: single_grapheme && cfg!(feature = "graphemes"),
+        });
+        assert_eq!(found, expected, "{src:?}");
+    }
+
+    // decomposed accent, as chars and escapes
+    check("e\u{301}", Mode::Char, Some((2, true)));
+    check(r"e\u{301}", Mode::Char, Some((2, true)));
+    // emoji ZWJ sequence, flag, CRLF
+    check("\u{1f469}\u{200d}\u{1f4bb}", Mode::Char, Some((3, true)));
+    check("\u{1f1f3}\u{1f1f4}", Mode::Char, Some((2, true)));
+    check("\u{1f1f3}\u{1f1f4}\u{1f1f3}", Mode::Char, Some((3, false)));
+    check(r"\r\n", Mode::Byte, Some((2, true)));
+    // more than one char
+    check("ab", Mode::Char, Some((2, false)));
+    check("e\u{301}a", Mode::Char, Some((3, false)));
+    check("ab", Mode::Byte, Some((2, false)));
+    // other errors
+    check("a", Mode::Char, None);
+    check("", Mode::Char, None);
+    check(r"a\q", Mode::Char, None);
+    check("a\u{e9}", Mode::Byte, None);
+    check("ab", Mode::Str, None);
+}
+
+#[test]
+fn test_escape_chars() {
+    fn check(src: &str, mode: Mode, expected: &str) {
+        let mut escaped = String::new();
+        escape_chars(src, mode, &EscapeStyle::default(), |s| escaped.push_str(s));
+        assert_eq!(escaped, expected);
+    }
+
+    check("e\u{301}", Mode::Char, r"\u{65}\u{301}");
+    check(r"a\n\u{1f980}", Mode::Char, r"\u{61}\u{a}\u{1f980}");
+    check(r"ab\xff", Mode::Byte, r"\x61\x62\xff");
+}
+
+#[test]
+fn test_check_lints_special_code_points() {
+    let config = LintConfig {
+        noncharacter: Level::Warn,
+        private_use: Level::Warn,
+        unassigned: Level::Warn,
+        ..LintConfig::default()
+    };
+
+    assert_lints(
+        &config,
+        "a\u{fffe}\u{fdd0}",
+        Mode::Str,
+        &[
+            (1..4, Lint::Noncharacter('\u{fffe}'), None),
+            (4..7, Lint::Noncharacter('\u{fdd0}'), None),
+        ],
+    );
+    assert_lints(
+        &config,
+        r"\u{10ffff}",
+        Mode::Char,
+        &[(0..10, Lint::Noncharacter('\u{10ffff}'), None)],
+    );
+    assert_lints(
+        &config,
+        "\u{e000}\\u{f0000}",
+        Mode::CStr,
+        &[
+            (0..3, Lint::PrivateUse('\u{e000}'), None),
+            (3..12, Lint::PrivateUse('\u{f0000}'), None),
+        ],
+    );
+    assert_lints(
+        &config,
+        "\u{f8ff}",
+        Mode::RawStr,
+        &[(0..3, Lint::PrivateUse('\u{f8ff}'), None)],
+    );
+    #[cfg(feature = "unassigned")]
+    assert_lints(
+        &config,
+        "\u{378}\\u{e0080}",
+        Mode::Str,
+        &[
+            (0..2, Lint::Unassigned('\u{378}'), None),
+            (2..11, Lint::Unassigned('\u{e0080}'), None),
+        ],
+    );
+    // escaped code points are reported as such
+    let mut escaped = Vec::new();
+    check_lints("\u{e000}\\u{f0000}", Mode::CStr, &config, |diag| {
+        escaped.push(diag.escaped)
+    });
+    assert_eq!(escaped, [false, true]);
+    // assigned chars, and the ends of the ranges
+    assert_lints(
+        &config,
+        "a\u{e9}\u{fdcf}\u{fdf0}\u{fffd}\u{f900}\u{1f980}\u{fffff}",
+        Mode::Str,
+        &[(19..23, Lint::Noncharacter('\u{fffff}'), None)],
+    );
+}
+
+#[test]
+fn test_check_lints_control_char() {
+    let config = LintConfig {
+        control_char: Level::Warn,
+        ..LintConfig::default()
+    };
+
+    assert_lints(
+        &config,
+        "\u{1b}[0m\u{7}",
+        Mode::Str,
+        &[
+            (0..1, Lint::ControlChar('\u{1b}'), Some(r"\x1b")),
+            (4..5, Lint::ControlChar('\u{7}'), Some(r"\x07")),
+        ],
+    );
+    assert_lints(
+        &config,
+        "\u{0}",
+        Mode::Str,
+        &[(0..1, Lint::ControlChar('\u{0}'), Some(r"\0"))],
+    );
+    assert_lints(
+        &config,
+        "\u{7f}",
+        Mode::Byte,
+        &[(0..1, Lint::ControlChar('\u{7f}'), Some(r"\x7f"))],
+    );
+    assert_lints(
+        &config,
+        "\u{85}",
+        Mode::CStr,
+        &[(0..2, Lint::ControlChar('\u{85}'), Some(r"\u{85}"))],
+    );
+    assert_lints(
+        &config,
+        "a\u{1b}",
+        Mode::RawStr,
+        &[(1..2, Lint::ControlChar('\u{1b}'), None)],
+    );
+    assert_lints(
+        &config,
+        "\u{9b}",
+        Mode::RawCStr,
+        &[(0..2, Lint::ControlChar('\u{9b}'), None)],
+    );
+    assert_lints(
+        &config,
+        "\u{c}",
+        Mode::RawByteStr,
+        &[(0..1, Lint::ControlChar('\u{c}'), None)],
+    );
+    // tabs, newlines and escapes are fine
+    assert_lints(&config, "a\u{9}b\u{a}c\\x1b\\u{85}", Mode::Str, &[]);
+    assert_lints(&config, "\u{9}\u{a}", Mode::RawStr, &[]);
+}
+
+#[cfg(feature = "nfc")]
+#[test]
+fn test_nfc() {
+    use rustc_literal_escaper::{first_non_nfc, normalize_nfc};
+
+    fn check(src: &str, mode: Mode, range: Option<Range<usize>>, normalized: &str) {
+        assert_eq!(first_non_nfc(src, mode), range);
+        let mut out = String::new();
+        normalize_nfc(src, mode, |s| out.push_str(s)).unwrap();
+        assert_eq!(out, normalized);
+        assert_eq!(first_non_nfc(&out, mode), None);
+    }
+
+    check("caf\u{e9}", Mode::Str, None, "caf\u{e9}");
+    check(r"caf\u{e9}", Mode::Str, None, r"caf\u{e9}");
+    check("cafe\u{301}!", Mode::Str, Some(3..6), "caf\u{e9}!");
+    check("cafe\u{301}", Mode::RawStr, Some(3..6), "caf\u{e9}");
+    // escaped parts stay escaped
+    check(r"cafe\u{301}\n", Mode::Str, Some(3..11), r"caf\u{e9}\n");
+    check(r"\x41\u{30a}", Mode::Str, Some(0..11), r"\u{c5}");
+    // combining marks are reordered, singletons are replaced
+    check(
+        "a\u{301}\u{323}b",
+        Mode::Str,
+        Some(0..5),
+        "\u{1ea1}\u{301}b",
+    );
+    check("\u{212b}", Mode::Str, Some(0..3), "\u{c5}");
+    // string continuations between parts are kept
+    check("e\\\n  a\u{300}", Mode::Str, Some(5..8), "e\\\n  \u{e0}");
+    // other kinds of literals are not checked
+    check("e\u{301}", Mode::CStr, None, "e\u{301}");
+
+    assert_eq!(
+        normalize_nfc(r"\q", Mode::Str, |_| panic!()),
+        Err((0..2, EscapeError::InvalidEscape))
+    );
+}
+
+#[test]
+fn test_levels() {
+    fn errors(src: &str, config: &LintConfig) -> Vec<(Range<usize>, EscapeError, Level)> {
+        let mut errors = Vec::new();
+        check_for_errors_with_levels(src, Mode::Str, config, |range, err, level| {
+            errors.push((range, err, level))
+        });
+        errors
+    }
+
+    // warnings are warned about by default
+    let src = "\\\u{a}\u{a}x\\q";
+    let mut config = LintConfig::default();
+    assert_eq!(
+        errors(src, &config),
+        [
+            (0..3, EscapeError::MultipleSkippedLinesWarning, Level::Warn),
+            (4..6, EscapeError::InvalidEscape, Level::Deny),
+        ]
+    );
+    config.multiple_skipped_lines = Level::Allow;
+    assert_eq!(
+        errors(src, &config),
+        [(4..6, EscapeError::InvalidEscape, Level::Deny)]
+    );
+    config.multiple_skipped_lines = Level::Deny;
+    config.unskipped_whitespace = Level::Allow;
+    assert_eq!(
+        errors(src, &config)[0],
+        (0..3, EscapeError::MultipleSkippedLinesWarning, Level::Deny)
+    );
+
+    let mut errors = Vec::new();
+    check_for_errors_in_edition_with_levels(
+        "a",
+        Mode::CStr,
+        Edition::Edition2018,
+        &config,
+        |range, err, level| errors.push((range, err, level)),
+    );
+    assert_eq!(
+        errors,
+        [(0..1, EscapeError::UnavailableInEdition, Level::Deny)]
+    );
+
+    // the scanner reports at the same levels
+    let src = "(\"\\\u{a}\u{a}x\", foo\"y\")";
+    let mut errors = Vec::new();
+    check_literals_with_levels(src, &config, |range, err, level| {
+        errors.push((range, err, level))
+    });
+    assert_eq!(
+        errors,
+        [
+            (2..5, EscapeError::MultipleSkippedLinesWarning, Level::Deny),
+            (9..12, EscapeError::ReservedPrefix, Level::Deny),
+        ]
+    );
+    config.multiple_skipped_lines = Level::Allow;
+    let mut errors = Vec::new();
+    check_literals_in_edition_with_levels(
+        src,
+        Edition::Edition2018,
+        &config,
+        |range, err, level| errors.push((range, err, level)),
+    );
+    assert_eq!(errors, []);
+
+    // lints are allowed by default
+    let src = "\u{200b}\\x41";
+    check_lints(src, Mode::Str, &LintConfig::default(), |diag| {
+        panic!("{diag:?}")
+    });
+    let mut lints = Vec::new();
+    let config = LintConfig {
+        invisible_char: InvisibleChars {
+            zero_width: Level::Deny,
+            ..InvisibleChars::all(Level::Warn)
+        },
+        escaped_printable_ascii: Level::Warn,
+        ..LintConfig::default()
+    };
+    check_lints(src, Mode::Str, &config, |diag| {
+        lints.push((diag.range, diag.lint, diag.level))
+    });
+    assert_eq!(
+        lints,
+        [
+            (
+                0..3,
+                Lint::InvisibleChar(InvisibleClass::ZeroWidth),
+                Level::Deny
+            ),
+            (3..7, Lint::EscapedPrintableAscii, Level::Warn),
+        ]
+    );
+    assert_eq!(config.lint_level(Lint::MixedCaseHex), Level::Allow);
+    assert_eq!(
+        config.lint_level(Lint::InvisibleChar(InvisibleClass::SoftHyphen)),
+        Level::Warn
+    );
+}